@@ -0,0 +1,33 @@
+use common::{
+    input::{self, Source},
+    Solution,
+};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let input = input::load(&Source::Default(S::DAY)).unwrap_or_else(|err| panic!("{}", err));
+    let parsed = S::parse(&input).unwrap_or_else(|err| panic!("{}", err));
+
+    let mut group = c.benchmark_group(format!("day{}", S::DAY));
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day1::Day1>(c);
+    bench_day::<day2::Day2>(c);
+    bench_day::<day3::Day3>(c);
+    bench_day::<day4::Day4>(c);
+    bench_day::<day5::Day5>(c);
+    bench_day::<day6::Day6>(c);
+    bench_day::<day7::Day7>(c);
+    bench_day::<day8::Day8>(c);
+    bench_day::<day9::Day9>(c);
+    bench_day::<day10::Day10>(c);
+    bench_day::<day11::Day11>(c);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,120 @@
+use std::{collections::BTreeMap, fmt, fs, io, path::Path};
+
+/// Accepted answers for each day's real `data/input`, one `<day> <part> <answer>` per line.
+pub const ANSWERS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers");
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Unrecorded,
+    Correct,
+    Incorrect(String),
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Unrecorded => Ok(()),
+            Verdict::Correct => write!(f, "✔"),
+            Verdict::Incorrect(expected) => write!(f, "✘ (expected {})", expected),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RecordedAnswers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+impl RecordedAnswers {
+    fn parse(contents: &str) -> Result<Self, String> {
+        let mut recorded = Self::default();
+
+        for line in contents.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let split: Vec<&str> = line.split_whitespace().collect();
+            let [day, part, answer] = split[..] else {
+                return Err(format!("Invalid answers line: {}", line));
+            };
+            let (day, part) = match (day.parse(), part.parse()) {
+                (Ok(day), Ok(part)) => (day, part),
+                _ => return Err(format!("Invalid answers line: {}", line)),
+            };
+
+            recorded.record(day, part, answer);
+        }
+
+        Ok(recorded)
+    }
+
+    /// Loads the answers file, treating a missing file as having nothing recorded.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("Failed to read answers file. Error: {}", err)),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: &str) {
+        self.answers.insert((day, part), answer.into());
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unrecorded,
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Incorrect(expected.into()),
+        }
+    }
+}
+
+impl fmt::Display for RecordedAnswers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "# <day> <part> <accepted answer for the day's data/input>"
+        )?;
+        for ((day, part), answer) in &self.answers {
+            writeln!(f, "{} {} {}", day, part, answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_and_checks_answers() {
+        let mut recorded = RecordedAnswers::parse("# comment\n7 2 5905\n\n7 1 6440\n").unwrap();
+        recorded.record(1, 1, "142");
+
+        let recorded = RecordedAnswers::parse(&recorded.to_string()).unwrap();
+
+        assert_eq!(recorded.check(7, 1, "6440"), Verdict::Correct);
+        assert_eq!(
+            recorded.check(7, 2, "5906"),
+            Verdict::Incorrect("5905".into())
+        );
+        assert_eq!(recorded.check(1, 1, "142"), Verdict::Correct);
+        assert_eq!(recorded.check(1, 2, "281"), Verdict::Unrecorded);
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        assert!(RecordedAnswers::parse("7 two 5905").is_err());
+        assert!(RecordedAnswers::parse("7 2").is_err());
+    }
+}
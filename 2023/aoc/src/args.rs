@@ -0,0 +1,156 @@
+pub const USAGE: &str = "\
+Usage: aoc run --day <DAY> [--part <1|2|both>] [--time] [--format <text|json>] [INPUT | -]
+       aoc record --day <DAY> [--part <1|2|both>]
+       aoc verify [--day <DAY>]
+       aoc bench [--day <DAY>] [--part <1|2|both>] [--runs <N>] [OUTPUT]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+    Both,
+}
+
+impl Part {
+    pub fn includes(&self, part: u8) -> bool {
+        matches!(
+            (self, part),
+            (Part::Both, _) | (Part::One, 1) | (Part::Two, 2)
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug)]
+pub enum Command {
+    Run {
+        day: u8,
+        part: Part,
+        input_path: Option<String>,
+        time: bool,
+        format: Format,
+    },
+    Record {
+        day: u8,
+        part: Part,
+    },
+    Verify {
+        day: Option<u8>,
+    },
+    Bench {
+        day: Option<u8>,
+        part: Part,
+        runs: usize,
+        output_path: Option<String>,
+    },
+}
+
+#[derive(Debug, Default)]
+struct Options {
+    day: Option<u8>,
+    part: Option<Part>,
+    input_path: Option<String>,
+    time: bool,
+    runs: Option<usize>,
+    format: Option<Format>,
+}
+
+fn parse_day(value: Option<String>) -> Result<u8, String> {
+    let value = value.ok_or("Missing value for --day")?;
+    value.parse().map_err(|_| format!("Invalid day: {}", value))
+}
+
+fn parse_part(value: Option<String>) -> Result<Part, String> {
+    match value.as_deref() {
+        Some("1") => Ok(Part::One),
+        Some("2") => Ok(Part::Two),
+        Some("both") => Ok(Part::Both),
+        Some(value) => Err(format!("Invalid part: {}", value)),
+        None => Err("Missing value for --part".into()),
+    }
+}
+
+fn parse_format(value: Option<String>) -> Result<Format, String> {
+    match value.as_deref() {
+        Some("text") => Ok(Format::Text),
+        Some("json") => Ok(Format::Json),
+        Some(value) => Err(format!("Invalid format: {}", value)),
+        None => Err("Missing value for --format".into()),
+    }
+}
+
+fn parse_runs(value: Option<String>) -> Result<usize, String> {
+    let value = value.ok_or("Missing value for --runs")?;
+    match value.parse() {
+        Ok(runs) if runs > 0 => Ok(runs),
+        _ => Err(format!("Invalid number of runs: {}", value)),
+    }
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => options.day = Some(parse_day(args.next())?),
+            "--part" | "-p" => options.part = Some(parse_part(args.next())?),
+            "--time" | "-t" => options.time = true,
+            "--format" | "-f" => options.format = Some(parse_format(args.next())?),
+            "--runs" | "-r" => options.runs = Some(parse_runs(args.next())?),
+            _ if arg.starts_with('-') && arg != "-" => {
+                return Err(format!("Unknown option: {}", arg))
+            }
+            _ if options.input_path.is_none() => options.input_path = Some(arg),
+            _ => return Err(format!("Unexpected argument: {}", arg)),
+        }
+    }
+
+    Ok(options)
+}
+
+fn no_input_path(options: &Options) -> Result<(), String> {
+    match &options.input_path {
+        Some(arg) => Err(format!("Unexpected argument: {}", arg)),
+        None => Ok(()),
+    }
+}
+
+pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let command = args.next();
+    let options = parse_options(args)?;
+    let day = options.day.ok_or("Please provide a day with --day");
+
+    match command.as_deref() {
+        Some("run") => Ok(Command::Run {
+            day: day?,
+            part: options.part.unwrap_or(Part::Both),
+            input_path: options.input_path,
+            time: options.time,
+            format: options.format.unwrap_or(Format::Text),
+        }),
+        Some("record") => {
+            no_input_path(&options)?;
+            Ok(Command::Record {
+                day: day?,
+                part: options.part.unwrap_or(Part::Both),
+            })
+        }
+        Some("verify") => {
+            no_input_path(&options)?;
+            Ok(Command::Verify { day: options.day })
+        }
+        Some("bench") => Ok(Command::Bench {
+            day: options.day,
+            part: options.part.unwrap_or(Part::Both),
+            runs: options.runs.unwrap_or(1),
+            output_path: options.input_path,
+        }),
+        Some(command) => Err(format!("Unknown command: {}", command)),
+        None => Err("Please provide a command".into()),
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{args::Part, days::Day};
+use common::parse::ParseError;
+use std::{fmt::Write, time::Duration};
+
+pub fn format_elapsed(elapsed: Duration) -> String {
+    format!("{:.3} ms", elapsed.as_secs_f64() * 1000.0)
+}
+
+/// Fastest time seen for each stage of a day across all runs.
+#[derive(Debug, Default)]
+pub struct Timings {
+    pub day: u8,
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1.unwrap_or_default() + self.part2.unwrap_or_default()
+    }
+}
+
+fn fastest(best: Option<Duration>, elapsed: Duration) -> Option<Duration> {
+    Some(best.map_or(elapsed, |best| best.min(elapsed)))
+}
+
+pub fn bench(day: &Day, part: Part, runs: usize, input: &str) -> Result<Timings, ParseError> {
+    let mut timings = Timings {
+        day: day.number,
+        parse: Duration::MAX,
+        ..Default::default()
+    };
+
+    for _ in 0..runs {
+        let solved = (day.run)(input, part)?;
+        timings.parse = timings.parse.min(solved.parse_elapsed);
+
+        for answer in solved.answers {
+            match answer.part {
+                1 => timings.part1 = fastest(timings.part1, answer.elapsed),
+                _ => timings.part2 = fastest(timings.part2, answer.elapsed),
+            }
+        }
+    }
+
+    Ok(timings)
+}
+
+/// Markdown table comparing the stages of every benchmarked day.
+pub fn table(all_timings: &[Timings]) -> String {
+    let skipped = || "-".to_string();
+    let mut table = String::from("| Day | Parse | Part 1 | Part 2 | Total |\n");
+    table.push_str("| --: | --: | --: | --: | --: |\n");
+
+    for timings in all_timings {
+        writeln!(
+            table,
+            "| {} | {} | {} | {} | {} |",
+            timings.day,
+            format_elapsed(timings.parse),
+            timings.part1.map_or_else(skipped, format_elapsed),
+            timings.part2.map_or_else(skipped, format_elapsed),
+            format_elapsed(timings.total()),
+        )
+        .unwrap();
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_marks_skipped_parts() {
+        let timings = Timings {
+            day: 7,
+            parse: Duration::from_micros(1500),
+            part1: None,
+            part2: Some(Duration::from_millis(2)),
+        };
+
+        assert_eq!(
+            table(&[timings]),
+            "| Day | Parse | Part 1 | Part 2 | Total |\n\
+             | --: | --: | --: | --: | --: |\n\
+             | 7 | 1.500 ms | - | 2.000 ms | 3.500 ms |\n"
+        );
+    }
+}
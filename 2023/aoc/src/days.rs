@@ -0,0 +1,80 @@
+use crate::args::Part;
+use common::{parse::ParseError, Solution};
+use std::time::{Duration, Instant};
+
+pub struct Answer {
+    pub part: u8,
+    pub value: String,
+    pub elapsed: Duration,
+}
+
+/// Answers for the requested parts, along with how long each stage took.
+pub struct Solved {
+    pub parse_elapsed: Duration,
+    pub answers: Vec<Answer>,
+}
+
+pub struct Day {
+    pub number: u8,
+    pub run: fn(&str, Part) -> Result<Solved, ParseError>,
+}
+
+fn timed<T>(stage: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = stage();
+    (result, start.elapsed())
+}
+
+fn run<S: Solution>(input: &str, part: Part) -> Result<Solved, ParseError> {
+    let (parsed, parse_elapsed) = timed(|| S::parse(input));
+    let parsed = parsed?;
+
+    let mut answers = vec![];
+    if part.includes(1) {
+        let (value, elapsed) = timed(|| S::part1(&parsed).to_string());
+        answers.push(Answer {
+            part: 1,
+            value,
+            elapsed,
+        });
+    }
+    if part.includes(2) {
+        let (value, elapsed) = timed(|| S::part2(&parsed).to_string());
+        answers.push(Answer {
+            part: 2,
+            value,
+            elapsed,
+        });
+    }
+
+    Ok(Solved {
+        parse_elapsed,
+        answers,
+    })
+}
+
+const fn day<S: Solution>() -> Day {
+    Day {
+        number: S::DAY,
+        run: run::<S>,
+    }
+}
+
+// Adding a day is a matter of adding its crate as a dependency and registering it here
+pub const DAYS: &[Day] = &[
+    day::<day1::Day1>(),
+    day::<day2::Day2>(),
+    day::<day3::Day3>(),
+    day::<day4::Day4>(),
+    day::<day5::Day5>(),
+    day::<day6::Day6>(),
+    day::<day7::Day7>(),
+    day::<day8::Day8>(),
+    day::<day9::Day9>(),
+    day::<day10::Day10>(),
+    day::<day11::Day11>(),
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
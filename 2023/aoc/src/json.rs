@@ -0,0 +1,83 @@
+use crate::days::Solved;
+use std::time::Duration;
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            ch if ch.is_control() => escaped.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+// Integer answers stay numbers so scripts don't have to convert them
+fn answer_value(value: &str) -> String {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    if !digits.is_empty() && digits.chars().all(|ch| ch.is_ascii_digit()) {
+        value.into()
+    } else {
+        format!("\"{}\"", escape(value))
+    }
+}
+
+fn milliseconds(elapsed: Duration) -> String {
+    format!("{:.3}", elapsed.as_secs_f64() * 1000.0)
+}
+
+/// A single line JSON record for one day: how long parsing took and each part's answer.
+pub fn record(day: u8, solved: &Solved) -> String {
+    let answers: Vec<String> = solved
+        .answers
+        .iter()
+        .map(|answer| {
+            format!(
+                "{{\"part\":{},\"answer\":{},\"elapsed_ms\":{}}}",
+                answer.part,
+                answer_value(&answer.value),
+                milliseconds(answer.elapsed)
+            )
+        })
+        .collect();
+
+    format!(
+        "{{\"day\":{},\"parse_ms\":{},\"answers\":[{}]}}",
+        day,
+        milliseconds(solved.parse_elapsed),
+        answers.join(",")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::Answer;
+
+    #[test]
+    fn formats_numeric_and_text_answers() {
+        let answer = Answer {
+            part: 2,
+            value: "247885995".into(),
+            elapsed: Duration::from_micros(1250),
+        };
+        let solved = Solved {
+            parse_elapsed: Duration::from_micros(400),
+            answers: vec![
+                Answer {
+                    part: 1,
+                    value: "say \"hi\"".into(),
+                    elapsed: Duration::from_micros(20),
+                },
+                answer,
+            ],
+        };
+        assert_eq!(
+            record(7, &solved),
+            r#"{"day":7,"parse_ms":0.400,"answers":[{"part":1,"answer":"say \"hi\"","elapsed_ms":0.020},{"part":2,"answer":247885995,"elapsed_ms":1.250}]}"#
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use common::input::{self, Source};
+use std::{env, fs, process::exit};
+
+mod answers;
+mod args;
+mod bench;
+mod days;
+mod json;
+
+use answers::{RecordedAnswers, Verdict, ANSWERS_PATH};
+use args::{Command, Format, Part, USAGE};
+use bench::format_elapsed;
+use days::{Day, Solved};
+
+fn read_input(source: &Source) -> String {
+    match input::load(source) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}", err);
+            exit(1)
+        }
+    }
+}
+
+fn solve(day: &Day, input: &str, part: Part) -> Solved {
+    match (day.run)(input, part) {
+        Ok(solved) => solved,
+        Err(err) => {
+            eprintln!("{}", err);
+            exit(1)
+        }
+    }
+}
+
+fn get_day(number: u8) -> &'static Day {
+    match days::get(number) {
+        Some(day) => day,
+        None => {
+            eprintln!("Day {} is not registered", number);
+            exit(1)
+        }
+    }
+}
+
+fn load_answers() -> RecordedAnswers {
+    match RecordedAnswers::load(ANSWERS_PATH) {
+        Ok(recorded) => recorded,
+        Err(err) => {
+            eprintln!("{}", err);
+            exit(1)
+        }
+    }
+}
+
+// Recorded answers only apply to the day's real input, not to examples
+fn is_real_input(day: &Day, source: &Source) -> bool {
+    match source {
+        Source::Default(_) => true,
+        Source::Stdin => false,
+        Source::Path(path) => match (
+            fs::canonicalize(path),
+            fs::canonicalize(input::default_path(day.number)),
+        ) {
+            (Ok(given), Ok(real)) => given == real,
+            _ => false,
+        },
+    }
+}
+
+fn run(day: &Day, part: Part, source: &Source, time: bool, format: Format) {
+    let input = read_input(source);
+    let recorded = if is_real_input(day, source) {
+        load_answers()
+    } else {
+        RecordedAnswers::default()
+    };
+
+    let solved = solve(day, &input, part);
+
+    if format == Format::Json {
+        println!("{}", json::record(day.number, &solved));
+        return;
+    }
+
+    if time {
+        println!("Parse: {}", format_elapsed(solved.parse_elapsed));
+    }
+
+    for answer in solved.answers {
+        match recorded.check(day.number, answer.part, &answer.value) {
+            Verdict::Unrecorded => print!("Part {}: {}", answer.part, answer.value),
+            verdict => print!("Part {}: {} {}", answer.part, answer.value, verdict),
+        }
+        if time {
+            print!(" ({})", format_elapsed(answer.elapsed));
+        }
+        println!();
+    }
+}
+
+fn record(day: &Day, part: Part) {
+    let input = read_input(&Source::Default(day.number));
+    let mut recorded = load_answers();
+
+    for answer in solve(day, &input, part).answers {
+        recorded.record(day.number, answer.part, &answer.value);
+        println!(
+            "Recorded day {} part {}: {}",
+            day.number, answer.part, answer.value
+        );
+    }
+
+    if let Err(err) = recorded.save(ANSWERS_PATH) {
+        eprintln!("Failed to save answers file. Error: {}", err);
+        exit(1)
+    }
+}
+
+fn verify(days: &[&Day]) {
+    let recorded = load_answers();
+    let mut num_incorrect = 0;
+
+    for day in days {
+        let part = match (
+            recorded.get(day.number, 1).is_some(),
+            recorded.get(day.number, 2).is_some(),
+        ) {
+            (true, true) => Part::Both,
+            (true, false) => Part::One,
+            (false, true) => Part::Two,
+            (false, false) => continue,
+        };
+
+        let input = read_input(&Source::Default(day.number));
+
+        for answer in solve(day, &input, part).answers {
+            let verdict = recorded.check(day.number, answer.part, &answer.value);
+            if matches!(verdict, Verdict::Incorrect(_)) {
+                num_incorrect += 1;
+            }
+            println!(
+                "Day {} part {}: {} {}",
+                day.number, answer.part, answer.value, verdict
+            );
+        }
+    }
+
+    if num_incorrect > 0 {
+        eprintln!("{} answer(s) differ from the recorded ones", num_incorrect);
+        exit(1)
+    }
+}
+
+fn bench(days: &[&Day], part: Part, runs: usize, output_path: Option<String>) {
+    let all_timings: Vec<_> = days
+        .iter()
+        .map(|day| {
+            let input = read_input(&Source::Default(day.number));
+            bench::bench(day, part, runs, &input).unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                exit(1)
+            })
+        })
+        .collect();
+
+    let table = bench::table(&all_timings);
+    print!("{}", table);
+
+    if let Some(output_path) = output_path {
+        if let Err(err) = fs::write(output_path, table) {
+            eprintln!("Failed to write benchmark table. Error: {}", err);
+            exit(1)
+        }
+    }
+}
+
+fn all_days() -> Vec<&'static Day> {
+    days::DAYS.iter().collect()
+}
+
+fn main() {
+    let command = match args::parse(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, USAGE);
+            exit(1)
+        }
+    };
+
+    match command {
+        Command::Run {
+            day,
+            part,
+            input_path,
+            time,
+            format,
+        } => run(
+            get_day(day),
+            part,
+            &Source::from_arg(input_path.as_deref(), day),
+            time,
+            format,
+        ),
+        Command::Record { day, part } => record(get_day(day), part),
+        Command::Verify { day: Some(day) } => verify(&[get_day(day)]),
+        Command::Verify { day: None } => verify(&all_days()),
+        Command::Bench {
+            day,
+            part,
+            runs,
+            output_path,
+        } => {
+            let days = match day {
+                Some(day) => vec![get_day(day)],
+                None => all_days(),
+            };
+            bench(&days, part, runs, output_path)
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{
+    input::{self, InputError, Source},
+    parse::ParseError,
+};
+use std::{env, fmt, process::ExitCode, str::FromStr};
+
+/// Why a day's tool stopped. Usage problems are shown along with the usage text.
+#[derive(Debug)]
+pub enum CliError {
+    /// `-h` or `--help`
+    Help,
+    Usage(String),
+    Input(InputError),
+    Parse(ParseError),
+    Failed(String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CliError::Help => write!(f, "Help requested"),
+            CliError::Usage(err) | CliError::Failed(err) => write!(f, "{}", err),
+            CliError::Input(err) => write!(f, "{}", err),
+            CliError::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<InputError> for CliError {
+    fn from(err: InputError) -> Self {
+        CliError::Input(err)
+    }
+}
+
+impl From<ParseError> for CliError {
+    fn from(err: ParseError) -> Self {
+        CliError::Parse(err)
+    }
+}
+
+/// A tool's command line: options are read one at a time, and the one positional argument is
+/// kept as the input path.
+#[derive(Debug)]
+pub struct Args {
+    args: std::vec::IntoIter<String>,
+    input_path: Option<String>,
+}
+
+impl Args {
+    pub fn new(args: impl IntoIterator<Item = String>) -> Args {
+        Args {
+            args: args.into_iter().collect::<Vec<_>>().into_iter(),
+            input_path: None,
+        }
+    }
+
+    /// The first argument as it is, for tools that take a command before their options.
+    pub fn command(&mut self) -> Result<String, CliError> {
+        match self.args.next() {
+            Some(arg) if arg == "-h" || arg == "--help" => Err(CliError::Help),
+            Some(command) => Ok(command),
+            None => Err(CliError::Usage("Missing command".into())),
+        }
+    }
+
+    /// The next option, or `None` once every argument has been read.
+    pub fn option(&mut self) -> Result<Option<String>, CliError> {
+        for arg in self.args.by_ref() {
+            match arg.as_str() {
+                "-h" | "--help" => return Err(CliError::Help),
+                _ if arg.starts_with('-') && arg != "-" => return Ok(Some(arg)),
+                _ if self.input_path.is_none() => self.input_path = Some(arg),
+                _ => return Err(CliError::Usage(format!("Unexpected argument: {}", arg))),
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// The value following `option`.
+    pub fn value(&mut self, option: &str) -> Result<String, CliError> {
+        self.args
+            .next()
+            .ok_or_else(|| CliError::Usage(format!("Missing value for {}", option)))
+    }
+
+    pub fn parse_value<T: FromStr>(&mut self, option: &str) -> Result<T, CliError>
+    where
+        T::Err: fmt::Display,
+    {
+        let value = self.value(option)?;
+        value.parse().map_err(|err| {
+            CliError::Usage(format!("Invalid value for {} '{}': {}", option, value, err))
+        })
+    }
+
+    /// Loads the input from the path given, stdin for `-`, or else `day`'s real input.
+    pub fn input(&self, day: u8) -> Result<String, CliError> {
+        let source = Source::from_arg(self.input_path.as_deref(), day);
+        Ok(input::load(&source)?)
+    }
+}
+
+pub fn unknown_option(option: &str) -> CliError {
+    CliError::Usage(format!("Unknown option: {}", option))
+}
+
+/// Runs a tool on the process's arguments, printing `usage` for help or usage problems and any
+/// other error on its own.
+pub fn run(usage: &str, tool: impl FnOnce(Args) -> Result<(), CliError>) -> ExitCode {
+    match tool(Args::new(env::args().skip(1))) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(CliError::Help) => {
+            println!("{}", usage);
+            ExitCode::SUCCESS
+        }
+        Err(CliError::Usage(err)) => {
+            eprintln!("{}\n\n{}", err, usage);
+            ExitCode::FAILURE
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Args {
+        Args::new(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn reads_options_around_the_input_path() {
+        let mut parsed = args(&["--from", "soil", "data/test", "--lookup", "12"]);
+
+        assert_eq!(parsed.option().unwrap().as_deref(), Some("--from"));
+        assert_eq!(parsed.value("--from").unwrap(), "soil");
+        assert_eq!(parsed.option().unwrap().as_deref(), Some("--lookup"));
+        assert_eq!(parsed.parse_value::<usize>("--lookup").unwrap(), 12);
+        assert_eq!(parsed.option().unwrap(), None);
+        assert_eq!(parsed.input_path.as_deref(), Some("data/test"));
+
+        let mut parsed = args(&["-", "--lookup", "x"]);
+        parsed.option().unwrap();
+        assert_eq!(
+            parsed
+                .parse_value::<usize>("--lookup")
+                .unwrap_err()
+                .to_string(),
+            "Invalid value for --lookup 'x': invalid digit found in string"
+        );
+        assert!(matches!(
+            args(&["a", "b"]).option(),
+            Err(CliError::Usage(_))
+        ));
+        assert!(matches!(args(&["--help"]).option(), Err(CliError::Help)));
+    }
+}
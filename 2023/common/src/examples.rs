@@ -0,0 +1,115 @@
+use crate::{
+    input::{self, Source},
+    Solution,
+};
+use std::{fs, path::Path};
+
+/// Name of the manifest, stored next to the example files in a day's `data/` directory.
+///
+/// Each non-empty line that isn't a `#` comment lists an example file, the part it exercises
+/// and the expected answer:
+///
+/// ```text
+/// part1-test 1 6440
+/// ```
+pub const MANIFEST: &str = "examples";
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub file: String,
+    pub part: u8,
+    pub expected: String,
+}
+
+pub fn parse_manifest(manifest: &str) -> Vec<Example> {
+    manifest
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let split: Vec<&str> = line.split_whitespace().collect();
+            match split[..] {
+                [file, part, expected] => Example {
+                    file: file.into(),
+                    part: part
+                        .parse()
+                        .unwrap_or_else(|_| panic!("Invalid part in manifest line: {}", line)),
+                    expected: expected.into(),
+                },
+                _ => panic!("Invalid manifest line: {}", line),
+            }
+        })
+        .collect()
+}
+
+/// Runs every example listed in `data_dir`'s manifest and panics with all mismatches.
+pub fn check<S: Solution>(data_dir: impl AsRef<Path>) {
+    let data_dir = data_dir.as_ref();
+    let manifest = fs::read_to_string(data_dir.join(MANIFEST))
+        .unwrap_or_else(|err| panic!("Failed to read {:?}: {}", data_dir.join(MANIFEST), err));
+
+    let examples = parse_manifest(&manifest);
+    assert!(
+        !examples.is_empty(),
+        "No examples listed for day {}",
+        S::DAY
+    );
+
+    let mut failures = vec![];
+    for example in examples {
+        let input = input::load(&Source::Path(data_dir.join(&example.file)))
+            .unwrap_or_else(|err| panic!("{}", err));
+        let parsed = match S::parse(&input) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                failures.push(format!("{} in {}", err, example.file));
+                continue;
+            }
+        };
+
+        let answer = match example.part {
+            1 => S::part1(&parsed).to_string(),
+            2 => S::part2(&parsed).to_string(),
+            part => panic!("Invalid part {} for example {}", part, example.file),
+        };
+
+        if answer != example.expected {
+            failures.push(format!(
+                "day {} part {} on {}: expected {}, got {}",
+                S::DAY,
+                example.part,
+                example.file,
+                example.expected,
+                answer
+            ));
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_manifest_skipping_comments_and_blank_lines() {
+        let manifest = "# file part answer\n\npart1-test 1 6440\n  part2-test 2 5905\n";
+
+        assert_eq!(
+            parse_manifest(manifest),
+            vec![
+                Example {
+                    file: "part1-test".into(),
+                    part: 1,
+                    expected: "6440".into(),
+                },
+                Example {
+                    file: "part2-test".into(),
+                    part: 2,
+                    expected: "5905".into(),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use std::{
+    fmt, fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+    /// The real input shipped in the day's `data/` directory.
+    Default(u8),
+}
+
+impl Source {
+    /// Interprets a command line argument, where `-` means stdin and no argument means the day's
+    /// default input.
+    pub fn from_arg(arg: Option<&str>, day: u8) -> Self {
+        match arg {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(path.into()),
+            None => Source::Default(day),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Path(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "stdin"),
+            Source::Default(day) => write!(f, "{}", default_path(*day).display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Read(Source, io::Error),
+    Empty(Source),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Read(source, err) => write!(f, "Failed to read {}: {}", source, err),
+            InputError::Empty(source) => write!(f, "Input from {} is empty", source),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+pub fn default_path(day: u8) -> PathBuf {
+    [
+        env!("CARGO_MANIFEST_DIR"),
+        "..",
+        &format!("day{}", day),
+        "data",
+        "input",
+    ]
+    .iter()
+    .collect()
+}
+
+/// Uses `\n` line endings throughout and ends the input with exactly one newline, so parsers
+/// never see `\r` or trailing blank lines.
+pub fn normalise(raw: &str) -> String {
+    let mut input = raw.replace("\r\n", "\n").replace('\r', "\n");
+    input.truncate(input.trim_end_matches('\n').len());
+    input.push('\n');
+    input
+}
+
+pub fn load(source: &Source) -> Result<String, InputError> {
+    let raw = match source {
+        Source::Path(path) => fs::read_to_string(path),
+        Source::Default(day) => fs::read_to_string(default_path(*day)),
+        Source::Stdin => {
+            let mut raw = String::new();
+            io::stdin().read_to_string(&mut raw).map(|_| raw)
+        }
+    }
+    .map_err(|err| InputError::Read(source.clone(), err))?;
+
+    if raw.trim().is_empty() {
+        return Err(InputError::Empty(source.clone()));
+    }
+
+    Ok(normalise(&raw))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalises_line_endings_and_trailing_newlines() {
+        assert_eq!(normalise("a\r\nb\rc\n\n\n"), "a\nb\nc\n");
+        assert_eq!(normalise("a\nb"), "a\nb\n");
+    }
+
+    #[test]
+    fn picks_source_from_arg() {
+        assert_eq!(Source::from_arg(Some("-"), 7), Source::Stdin);
+        assert_eq!(Source::from_arg(None, 7), Source::Default(7));
+        assert_eq!(
+            Source::from_arg(Some("data/test"), 7),
+            Source::Path("data/test".into())
+        );
+    }
+
+    #[test]
+    fn reports_missing_input_instead_of_exiting() {
+        let missing = Source::Path("does/not/exist".into());
+        assert!(matches!(load(&missing), Err(InputError::Read(..))));
+    }
+}
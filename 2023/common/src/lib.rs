@@ -0,0 +1,24 @@
+use parse::ParseError;
+use std::fmt::Display;
+
+pub mod cli;
+pub mod examples;
+pub mod input;
+pub mod parse;
+
+/// A day's puzzle, split into typed stages.
+///
+/// The input is parsed once into a day-specific model which is then shared by both parts, so the
+/// runner, tests and benchmarks can all drive every day the same way. Malformed input is reported
+/// by `parse` rather than panicking, so the parts can assume a well-formed model.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Output1;
+    fn part2(input: &Self::Input) -> Self::Output2;
+}
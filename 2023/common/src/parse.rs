@@ -0,0 +1,157 @@
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// Text that isn't a valid `what`, e.g. an unknown card
+    Invalid { what: &'static str, text: String },
+    /// A well-formed `what` that can't be used, e.g. an empty range
+    Unusable {
+        what: &'static str,
+        text: String,
+        reason: &'static str,
+    },
+    /// Something the line should contain isn't there
+    Missing { what: &'static str },
+    /// Valid on its own, but clashes with what an earlier line says
+    Conflict { what: &'static str, line: usize },
+}
+
+/// Where and why a day's input failed to parse, reported as e.g.
+/// `day7: line 42, column 3: invalid card 'X'`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "day{}: line {}, column {}: ",
+            self.day, self.line, self.column
+        )?;
+        match &self.kind {
+            ParseErrorKind::Invalid { what, text } => write!(f, "invalid {} '{}'", what, text),
+            ParseErrorKind::Unusable { what, text, reason } => {
+                write!(f, "unusable {} '{}': {}", what, text, reason)
+            }
+            ParseErrorKind::Missing { what } => write!(f, "missing {}", what),
+            ParseErrorKind::Conflict { what, line } => {
+                write!(f, "{} conflicts with line {}", what, line)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// One line of a day's input, able to build errors pointing into itself.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub day: u8,
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// 1-based column of `part` when it's a slice of this line, otherwise the start of the line.
+    fn column_of(&self, part: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).wrapping_sub(start);
+        if offset <= self.text.len() {
+            offset + 1
+        } else {
+            1
+        }
+    }
+
+    pub fn error_at(&self, column: usize, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            day: self.day,
+            line: self.number,
+            column,
+            kind,
+        }
+    }
+
+    /// `text` should be a slice of the line so the column can be found.
+    pub fn invalid(&self, what: &'static str, text: &str) -> ParseError {
+        self.error_at(
+            self.column_of(text),
+            ParseErrorKind::Invalid {
+                what,
+                text: text.into(),
+            },
+        )
+    }
+
+    /// Like `invalid`, for text that reads fine but can't be used for `reason`.
+    pub fn unusable(&self, what: &'static str, text: &str, reason: &'static str) -> ParseError {
+        self.error_at(
+            self.column_of(text),
+            ParseErrorKind::Unusable {
+                what,
+                text: text.into(),
+                reason,
+            },
+        )
+    }
+
+    /// Reported at the end of the line, where the missing part was expected.
+    pub fn missing(&self, what: &'static str) -> ParseError {
+        self.error_at(self.text.len() + 1, ParseErrorKind::Missing { what })
+    }
+
+    /// Parses `text`, which should be a slice of the line, or reports it as an invalid `what`.
+    pub fn parse<T: FromStr>(&self, what: &'static str, text: &str) -> Result<T, ParseError> {
+        text.parse().map_err(|_| self.invalid(what, text))
+    }
+
+    /// Parses `text` if it's there, otherwise reports a missing `what`.
+    pub fn parse_next<T: FromStr>(
+        &self,
+        what: &'static str,
+        text: Option<&str>,
+    ) -> Result<T, ParseError> {
+        self.parse(what, text.ok_or_else(|| self.missing(what))?)
+    }
+}
+
+/// Numbers each line of `input` from 1 for error reporting.
+pub fn lines(day: u8, input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(move |(i, text)| Line {
+        day,
+        number: i + 1,
+        text,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_offending_text() {
+        let line = lines(7, "32T3K 765\nQQQJX 483").nth(1).unwrap();
+        let card = &line.text[4..5];
+
+        assert_eq!(
+            line.invalid("card", card).to_string(),
+            "day7: line 2, column 5: invalid card 'X'"
+        );
+        assert_eq!(
+            line.unusable("bid", &line.text[6..], "too low").to_string(),
+            "day7: line 2, column 7: unusable bid '483': too low"
+        );
+        assert_eq!(line.parse::<usize>("bid", card).unwrap_err().column, 5);
+        assert_eq!(
+            line.parse_next::<usize>("bid", None)
+                .unwrap_err()
+                .to_string(),
+            "day7: line 2, column 10: missing bid"
+        );
+    }
+}
@@ -1,6 +1,9 @@
-use std::env;
-use std::fs;
-use std::process::exit;
+use common::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub type Document = Vec<String>;
 
 const SPELLED_OUT_DIGITS: [(&str, u32); 9] = [
     ("one", 1),
@@ -18,15 +21,13 @@ fn spelled_out_digit(substr: &str, best_idx: &mut Option<usize>, reverse: bool)
     let mut res = None;
 
     for (s, d) in SPELLED_OUT_DIGITS.iter() {
-        let i = substr.find(s);
-        if i.is_some() {
-            let idx = i.unwrap();
+        if let Some(idx) = substr.find(s) {
             // println!("Found {} at {}", s, idx);
             if best_idx.is_none()
                 || reverse && idx > best_idx.unwrap()
                 || !reverse && idx < best_idx.unwrap()
             {
-                res = Some(d.clone());
+                res = Some(*d);
                 *best_idx = Some(idx)
             }
         }
@@ -80,13 +81,7 @@ fn get_first_digit(line: &str, reverse: bool) -> Option<u32> {
         }
     }
 
-    match spelled_out_digit(&line, &mut None, reverse) {
-        Some(d) => return Some(d),
-        None => {
-            // eprintln!("No digit found in string");
-            None
-        }
-    }
+    spelled_out_digit(line, &mut None, reverse)
 }
 
 fn get_left_digit(line: &str) -> u32 {
@@ -97,44 +92,76 @@ fn get_right_digit(line: &str) -> u32 {
     get_first_digit(line, true).unwrap()
 }
 
+// `None` for a line whose digits are all spelled out, which only count in part 2
+fn get_numeric_calibration_value_of_line(line: &str) -> Option<u32> {
+    let mut digits = line.chars().filter_map(|c| c.to_digit(10));
+    let l = digits.next()?;
+    let r = digits.next_back().unwrap_or(l);
+    Some(l * 10 + r)
+}
+
 fn get_calibration_value_of_line(line: &str) -> u32 {
     let l = get_left_digit(line);
     let r = get_right_digit(line);
-    let val = l * 10 + r;
-    println!("{} - {}", line, val);
-    val
-}
-
-fn get_calibration_value(input: String) -> u32 {
-    input
-        .lines()
-        .fold(0, |acc, line| acc + get_calibration_value_of_line(line))
+    l * 10 + r
 }
 
-fn read_input() -> String {
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        eprintln!("Please provide an input file path");
-        exit(1)
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Document;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Document, ParseError> {
+        // Every line needs a digit, written out or not, for the parts to find. The digit search
+        // steps through bytes as if they were characters, so only ASCII will do.
+        parse::lines(Self::DAY, input)
+            .map(|line| {
+                if let Some((i, c)) = line.text.char_indices().find(|(_, c)| !c.is_ascii()) {
+                    return Err(line.invalid("character", &line.text[i..i + c.len_utf8()]));
+                }
+                match get_first_digit(line.text, false) {
+                    Some(_) => Ok(line.text.into()),
+                    None => Err(line.missing("digit")),
+                }
+            })
+            .collect()
     }
 
-    let input_path = args.get(1).unwrap();
+    fn part1(document: &Document) -> u32 {
+        document
+            .iter()
+            .filter_map(|line| get_numeric_calibration_value_of_line(line))
+            .sum()
+    }
 
-    let open = fs::read_to_string(input_path);
-    match open {
-        Ok(input) => input,
-        Err(err) => {
-            eprintln!("Failed to open given file path. Error: {}", err);
-            exit(1)
-        }
+    fn part2(document: &Document) -> u32 {
+        document
+            .iter()
+            .fold(0, |acc, line| acc + get_calibration_value_of_line(line))
     }
 }
 
-fn main() {
-    let input = read_input();
-
-    let calibration_value = get_calibration_value(input);
-
-    println!("\nCalibration value: {}", calibration_value);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_without_digits_are_rejected() {
+        assert_eq!(
+            Day1::parse("1abc2\nabc").unwrap_err().to_string(),
+            "day1: line 2, column 4: missing digit"
+        );
+        assert_eq!(
+            Day1::parse("a1٣").unwrap_err().to_string(),
+            "day1: line 1, column 3: invalid character '٣'"
+        );
+
+        let document = Day1::parse("eightwothree\n7pqr").unwrap();
+        assert_eq!(Day1::part1(&document), 77);
+        assert_eq!(Day1::part2(&document), 83 + 77);
+    }
 }
@@ -0,0 +1,7 @@
+use common::examples;
+use day1::Day1;
+
+#[test]
+fn examples() {
+    examples::check::<Day1>(concat!(env!("CARGO_MANIFEST_DIR"), "/data"));
+}
@@ -0,0 +1,333 @@
+use common::{
+    parse::{ParseError, ParseErrorKind},
+    Solution,
+};
+use grid::{Direction, Grid, Pos};
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TileType {
+    /*
+
+    | is a vertical pipe connecting north and south.
+    - is a horizontal pipe connecting east and west.
+    L is a 90-degree bend connecting north and east.
+    J is a 90-degree bend connecting north and west.
+    7 is a 90-degree bend connecting south and west.
+    F is a 90-degree bend connecting south and east.
+    . is ground; there is no pipe in this tile.
+    S is the starting position of the animal; there is a pipe on this tile, but your sketch doesn't show what shape the pipe has.
+
+    */
+    Vertical,
+    Horizontal,
+    NorthEastBend,
+    NorthWestBend,
+    SouthWestBend,
+    SouthEastBend,
+    Ground,
+    Start,
+    Covered(usize),
+}
+
+impl TileType {
+    const PIPES: [TileType; 6] = [
+        TileType::Vertical,
+        TileType::Horizontal,
+        TileType::NorthEastBend,
+        TileType::NorthWestBend,
+        TileType::SouthWestBend,
+        TileType::SouthEastBend,
+    ];
+
+    fn from_char(ch: char) -> Option<TileType> {
+        match ch {
+            '|' => Some(TileType::Vertical),
+            '-' => Some(TileType::Horizontal),
+            'L' => Some(TileType::NorthEastBend),
+            'J' => Some(TileType::NorthWestBend),
+            '7' => Some(TileType::SouthWestBend),
+            'F' => Some(TileType::SouthEastBend),
+            '.' => Some(TileType::Ground),
+            'S' => Some(TileType::Start),
+            _ => None,
+        }
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            TileType::Vertical => '|',
+            TileType::Horizontal => '-',
+            TileType::NorthEastBend => 'L',
+            TileType::NorthWestBend => 'J',
+            TileType::SouthWestBend => '7',
+            TileType::SouthEastBend => 'F',
+            TileType::Ground => '.',
+            TileType::Start => 'S',
+            TileType::Covered(_) => 'O',
+        }
+    }
+
+    // The two directions a pipe leads in
+    fn connections(self) -> Option<[Direction; 2]> {
+        match self {
+            TileType::Vertical => Some([Direction::Up, Direction::Down]),
+            TileType::Horizontal => Some([Direction::Left, Direction::Right]),
+            TileType::NorthEastBend => Some([Direction::Up, Direction::Right]),
+            TileType::NorthWestBend => Some([Direction::Up, Direction::Left]),
+            TileType::SouthWestBend => Some([Direction::Down, Direction::Left]),
+            TileType::SouthEastBend => Some([Direction::Down, Direction::Right]),
+            _ => None,
+        }
+    }
+
+    fn connects(self, direction: Direction) -> bool {
+        self.connections()
+            .is_some_and(|connections| connections.contains(&direction))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Tile {
+    tile_type: TileType,
+    part_of_loop: bool,
+}
+
+impl Tile {
+    fn new(tile_type: TileType) -> Tile {
+        Tile {
+            tile_type,
+            part_of_loop: false,
+        }
+    }
+}
+
+type Tiles = Grid<Tile>;
+
+#[derive(Clone, Debug)]
+pub struct Diagram {
+    tiles: Tiles,
+    starting_position: Pos,
+    loop_length: usize,
+}
+
+fn parse_input(input: &str) -> Result<Diagram, ParseError> {
+    let tiles = Grid::parse(Day10::DAY, input, "tile", |ch| {
+        TileType::from_char(ch).map(Tile::new)
+    })?;
+
+    let Some(starting_position) = tiles.position(|tile| tile.tile_type == TileType::Start) else {
+        return Err(ParseError {
+            day: Day10::DAY,
+            line: 1,
+            column: 1,
+            kind: ParseErrorKind::Missing {
+                what: "starting position 'S'",
+            },
+        });
+    };
+
+    Ok(Diagram {
+        tiles,
+        starting_position,
+        loop_length: 0,
+    })
+}
+
+impl Diagram {
+    fn tile_error(&self, pos: Pos, what: &'static str, reason: &'static str) -> ParseError {
+        ParseError {
+            day: Day10::DAY,
+            line: pos.row + 1,
+            column: pos.col + 1,
+            kind: ParseErrorKind::Unusable {
+                what,
+                text: self.tiles[pos].tile_type.to_char().into(),
+                reason,
+            },
+        }
+    }
+
+    fn replace_start_tile(&mut self) -> Result<(), ParseError> {
+        let pos = self.starting_position;
+
+        // Neighbouring pipes leading back into the start
+        let can_go: Vec<Direction> = Direction::ALL
+            .into_iter()
+            .filter(|direction| {
+                self.tiles
+                    .step(pos, *direction)
+                    .is_some_and(|next| self.tiles[next].tile_type.connects(direction.opposite()))
+            })
+            .collect();
+
+        // Exactly two neighbours have to connect for the start to be part of a loop
+        let start_tile = match can_go[..] {
+            [first, second] => TileType::PIPES
+                .into_iter()
+                .find(|pipe| pipe.connects(first) && pipe.connects(second)),
+            _ => None,
+        };
+
+        match start_tile {
+            Some(tile_type) => {
+                self.tiles[pos].tile_type = tile_type;
+                Ok(())
+            }
+            None => Err(self.tile_error(pos, "starting position", "no loop runs through it")),
+        }
+    }
+
+    // Follows the loop from the start, marking every tile on it, and returns its length
+    fn traverse(&mut self) -> Result<usize, ParseError> {
+        let mut pos = self.starting_position;
+        let mut direction = self.tiles[pos].tile_type.connections().unwrap()[0];
+        let mut distance = 0;
+
+        loop {
+            self.tiles[pos].part_of_loop = true;
+
+            let Some(next) = self.tiles.step(pos, direction) else {
+                return Err(self.tile_error(pos, "pipe", "leads off the diagram"));
+            };
+
+            let came_from = direction.opposite();
+            direction = match self.tiles[next].tile_type.connections() {
+                Some([first, second]) if first == came_from => second,
+                Some([first, second]) if second == came_from => first,
+                _ => return Err(self.tile_error(next, "pipe", "breaks the loop")),
+            };
+
+            pos = next;
+            distance += 1;
+
+            if pos == self.starting_position {
+                // Made it around!
+                return Ok(distance);
+            }
+        }
+    }
+
+    fn cover(&mut self, pos: Pos, marker: usize) -> bool {
+        let mut seen_edge = false;
+        let mut to_cover = vec![pos];
+
+        while let Some(pos) = to_cover.pop() {
+            if self.tiles[pos].tile_type != TileType::Ground {
+                continue;
+            }
+
+            self.tiles[pos].tile_type = TileType::Covered(marker);
+            seen_edge |= self.tiles.is_edge(pos);
+            to_cover.extend(self.tiles.neighbours8(pos));
+        }
+
+        seen_edge
+    }
+
+    fn cover_ground(&mut self) -> HashMap<usize, bool> {
+        // Graph search to find groups of ground
+        let mut mark_to_seen_edge: HashMap<usize, bool> = HashMap::new();
+        for i in 1.. {
+            let ground = match self
+                .tiles
+                .position(|tile| tile.tile_type == TileType::Ground)
+            {
+                Some(pos) => pos,
+                None => break,
+            };
+
+            let seen_edge = self.cover(ground, i);
+            if seen_edge {
+                mark_to_seen_edge.insert(i, seen_edge);
+            }
+        }
+
+        mark_to_seen_edge
+    }
+
+    // Blows every loop tile up to 3x3 so ground squeezed between pipes becomes reachable
+    fn triple_scale(&self) -> Diagram {
+        let (num_rows, num_cols): (usize, usize) = (self.tiles.num_rows(), self.tiles.num_cols());
+        let (new_num_rows, new_num_cols): (usize, usize) = (num_rows * 3, num_cols * 3);
+
+        let mut new_diagram = Diagram {
+            tiles: Grid::filled(new_num_rows, new_num_cols, Tile::new(TileType::Ground)),
+            starting_position: Pos::new(
+                self.starting_position.row * 3 + 1,
+                self.starting_position.col * 3 + 1,
+            ),
+            loop_length: self.loop_length * 3,
+        };
+
+        /*
+         *        .|.
+         *   | -> .|.
+         *        .|.
+         */
+        for (pos, tile) in self.tiles.iter().filter(|(_, tile)| tile.part_of_loop) {
+            let center = Pos::new(pos.row * 3 + 1, pos.col * 3 + 1);
+            new_diagram.tiles[center] = Tile {
+                tile_type: tile.tile_type,
+                part_of_loop: true,
+            };
+
+            for direction in tile.tile_type.connections().unwrap() {
+                let arm = match direction {
+                    Direction::Up | Direction::Down => TileType::Vertical,
+                    Direction::Left | Direction::Right => TileType::Horizontal,
+                };
+                let arm_pos = new_diagram.tiles.step(center, direction).unwrap();
+                new_diagram.tiles[arm_pos] = Tile {
+                    tile_type: arm,
+                    part_of_loop: true,
+                };
+            }
+        }
+
+        new_diagram
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Diagram;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Diagram, ParseError> {
+        let mut diagram = parse_input(input)?;
+
+        diagram.replace_start_tile()?;
+        diagram.loop_length = diagram.traverse()?;
+
+        Ok(diagram)
+    }
+
+    fn part1(diagram: &Diagram) -> usize {
+        diagram.loop_length / 2
+    }
+
+    fn part2(diagram: &Diagram) -> usize {
+        let mut scaled_diagram = diagram.triple_scale();
+
+        let mark_to_seen_edge = scaled_diagram.cover_ground();
+
+        let mut num_enclosed = 0;
+
+        for pos in diagram.tiles.positions() {
+            let center = Pos::new(pos.row * 3 + 1, pos.col * 3 + 1);
+
+            if let TileType::Covered(i) = scaled_diagram.tiles[center].tile_type {
+                if !mark_to_seen_edge.contains_key(&i) {
+                    num_enclosed += 1;
+                }
+            }
+        }
+
+        num_enclosed
+    }
+}
@@ -0,0 +1,7 @@
+use common::examples;
+use day10::Day10;
+
+#[test]
+fn examples() {
+    examples::check::<Day10>(concat!(env!("CARGO_MANIFEST_DIR"), "/data"));
+}
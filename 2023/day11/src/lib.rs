@@ -0,0 +1,131 @@
+use common::{parse::ParseError, Solution};
+use grid::{Grid, Pos};
+use std::collections::HashMap;
+
+// const SCALE: usize = 1_000_000;
+const SCALE: usize = 1;
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+enum Area {
+    EmptySpace,    // .
+    Galaxy(usize), // #
+}
+
+#[derive(Clone, Debug)]
+pub struct Image {
+    image: Grid<Area>,
+    galaxy_cords: HashMap<usize, Pos>,
+}
+
+// Each row with nothing but empty space is repeated SCALE more times
+fn expand_rows(image: &Grid<Area>) -> Grid<Area> {
+    let mut rows = vec![];
+
+    for row in image.rows() {
+        let all_space = row.iter().all(|col| *col == Area::EmptySpace);
+        let copies = if all_space { SCALE + 1 } else { 1 };
+
+        for _ in 0..copies {
+            rows.push(row.to_vec());
+        }
+    }
+
+    Grid::from_rows(rows)
+}
+
+impl Image {
+    fn new(image: Grid<Area>) -> Image {
+        let galaxy_cords = image
+            .iter()
+            .filter_map(|(pos, area)| match area {
+                Area::Galaxy(galaxy_num) => Some((*galaxy_num, pos)),
+                Area::EmptySpace => None,
+            })
+            .collect();
+
+        Image {
+            image,
+            galaxy_cords,
+        }
+    }
+
+    fn new_from_string(input: &str) -> Result<Image, ParseError> {
+        let mut galaxy_num = 0;
+        let image = Grid::parse(Day11::DAY, input, "area", |ch| match ch {
+            '.' => Some(Area::EmptySpace),
+            '#' => {
+                galaxy_num += 1;
+                Some(Area::Galaxy(galaxy_num))
+            }
+            _ => None,
+        })?;
+
+        Ok(Image::new(image))
+    }
+
+    fn expand(&self) -> Image {
+        // Columns are expanded as the rows of the transposed image
+        let expanded = expand_rows(&expand_rows(&self.image).transpose()).transpose();
+
+        Image::new(expanded)
+    }
+
+    fn get_galaxy_distances(&self) -> HashMap<(usize, usize), usize> {
+        let mut distances = HashMap::new();
+
+        let mut galaxy_nums: Vec<_> = self.galaxy_cords.keys().cloned().collect::<Vec<_>>();
+        galaxy_nums.sort();
+
+        for (i, galaxy1) in galaxy_nums.iter().enumerate() {
+            for galaxy2 in galaxy_nums[i + 1..].iter() {
+                let cord1 = self.galaxy_cords[galaxy1];
+                let cord2 = self.galaxy_cords[galaxy2];
+
+                let distance = cord1.row.abs_diff(cord2.row) + cord1.col.abs_diff(cord2.col);
+
+                distances.insert((*galaxy1, *galaxy2), distance);
+            }
+        }
+
+        distances
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Image;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Image, ParseError> {
+        Image::new_from_string(input)
+    }
+
+    fn part1(image: &Image) -> usize {
+        let expanded_image = image.expand();
+
+        let distances = expanded_image.get_galaxy_distances();
+
+        distances.values().sum()
+    }
+
+    fn part2(image: &Image) -> usize {
+        let expanded_image = image.expand();
+
+        let distances = image.get_galaxy_distances();
+        let distance_sum: usize = distances.values().sum();
+
+        let expanded_distances = expanded_image.get_galaxy_distances();
+        let expanded_distance_sum: usize = expanded_distances.values().sum();
+
+        // Each empty row or column counts once in the image and twice expanded, so the
+        // difference is what one extra copy adds
+        let diff = expanded_distance_sum - distance_sum;
+        let diff_scaled = diff * (1_000_000 - 1);
+
+        distance_sum + diff_scaled
+    }
+}
@@ -0,0 +1,7 @@
+use common::examples;
+use day11::Day11;
+
+#[test]
+fn examples() {
+    examples::check::<Day11>(concat!(env!("CARGO_MANIFEST_DIR"), "/data"));
+}
@@ -1,4 +1,8 @@
-use std::{env, fs, process::exit, str::FromStr};
+use common::{
+    parse::{self, Line, ParseError},
+    Solution,
+};
+use std::str::FromStr;
 
 #[derive(Debug)]
 enum CubeColor {
@@ -50,80 +54,61 @@ struct GameConfig {
     num_blue: usize,
 }
 
+// The bag loaded in the puzzle: 12 red, 13 green and 14 blue cubes
+const GAME_CONFIG: GameConfig = GameConfig {
+    num_red: 12,
+    num_green: 13,
+    num_blue: 14,
+};
+
 #[derive(Debug)]
-struct Games {
+pub struct Games {
     games: Vec<Game>,
     config: GameConfig,
 }
 
-fn parse_cube(cube: &str) -> Cube {
+fn parse_cube(line: &Line, cube: &str) -> Result<Cube, ParseError> {
     let mut split = cube.split_whitespace();
-    Cube {
-        number: split.next().unwrap().parse::<usize>().unwrap(),
-        color: CubeColor::from_str(split.next().unwrap()).unwrap(),
-    }
+    let number = line.parse_next("cube count", split.next())?;
+    let color = split.next().ok_or_else(|| line.missing("cube color"))?;
+
+    Ok(Cube {
+        number,
+        color: CubeColor::from_str(color).map_err(|_| line.invalid("cube color", color))?,
+    })
 }
 
-fn parse_input(input: &str, game_config: GameConfig) -> Games {
+fn parse_input(input: &str, game_config: GameConfig) -> Result<Games, ParseError> {
     let mut games: Vec<Game> = vec![];
 
-    for line in input.lines() {
-        // println!("{}", line);
+    for line in parse::lines(Day2::DAY, input) {
+        let mut split = line.text.split(":");
 
-        let mut split = line.split(":");
-
-        let id = split
-            .next()
-            .unwrap()
+        let game = split.next().unwrap_or_default();
+        let id = game
             .strip_prefix("Game ")
-            .unwrap()
-            .parse::<usize>()
-            .unwrap();
+            .ok_or_else(|| line.invalid("game", game))?;
+        let id = line.parse("game id", id)?;
 
         let mut rounds: Vec<Round> = vec![];
-        for cubes in split.next().unwrap().split(";") {
+        for cubes in split
+            .next()
+            .ok_or_else(|| line.missing("rounds"))?
+            .split(";")
+        {
             let mut round = Round { cubes: vec![] };
             for cube in cubes.split(",") {
-                round.cubes.push(parse_cube(cube));
+                round.cubes.push(parse_cube(&line, cube)?);
             }
             rounds.push(round);
         }
         games.push(Game { id, rounds });
     }
 
-    Games {
+    Ok(Games {
         games,
         config: game_config,
-    }
-}
-
-fn read_input() -> (String, GameConfig) {
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 5 {
-        eprintln!("Please provide an input file path");
-        exit(1)
-    }
-
-    let input_path = args.get(1).unwrap();
-
-    let open = fs::read_to_string(input_path);
-    let input = match open {
-        Ok(input) => input,
-        Err(err) => {
-            eprintln!("Failed to open given file path. Error: {}", err);
-            exit(1)
-        }
-    };
-
-    (
-        input,
-        GameConfig {
-            num_red: args.get(2).unwrap().parse().unwrap(),
-            num_green: args.get(3).unwrap().parse().unwrap(),
-            num_blue: args.get(4).unwrap().parse().unwrap(),
-        },
-    )
+    })
 }
 
 impl Round {
@@ -226,7 +211,7 @@ impl MinCubes {
     }
 }
 
-fn sum_of_mincubes_power(min_cubes: &Vec<MinCubes>) -> usize {
+fn sum_of_mincubes_power(min_cubes: &[MinCubes]) -> usize {
     let mut power = 0;
     for min_cube in min_cubes.iter() {
         power += min_cube.power();
@@ -234,18 +219,27 @@ fn sum_of_mincubes_power(min_cubes: &Vec<MinCubes>) -> usize {
     power
 }
 
-fn main() {
-    let (input, game_config) = read_input();
-    let games = parse_input(&input, game_config);
+pub struct Day2;
 
-    // let valid_games = games.valid();
-    // let valid_games_ids_sum = valid_games.into_iter().fold(0, |acc, g| acc + g.id);
-    // println!("\nValid games IDs sum: {}", valid_games_ids_sum)
+impl Solution for Day2 {
+    const DAY: u8 = 2;
 
-    let min_cubes = games.min_cubes();
-    // dbg!(min_cubes);
+    type Input = Games;
+    type Output1 = usize;
+    type Output2 = usize;
 
-    let sum = sum_of_mincubes_power(&min_cubes);
+    fn parse(input: &str) -> Result<Games, ParseError> {
+        parse_input(input, GAME_CONFIG)
+    }
+
+    fn part1(games: &Games) -> usize {
+        let valid_games = games.valid();
+        valid_games.into_iter().fold(0, |acc, g| acc + g.id)
+    }
 
-    println!("\nPower of min cubes: {}", sum)
+    fn part2(games: &Games) -> usize {
+        let min_cubes = games.min_cubes();
+
+        sum_of_mincubes_power(&min_cubes)
+    }
 }
@@ -0,0 +1,7 @@
+use common::examples;
+use day2::Day2;
+
+#[test]
+fn examples() {
+    examples::check::<Day2>(concat!(env!("CARGO_MANIFEST_DIR"), "/data"));
+}
@@ -0,0 +1,127 @@
+use common::{parse::ParseError, Solution};
+use grid::{Grid, Pos};
+use std::ops::Range;
+
+pub type Schematic = Grid<char>;
+
+type GearRatio = usize;
+type GearRatios = Vec<GearRatio>;
+type PartNumber = usize;
+type PartNumbers = Vec<PartNumber>;
+
+// A run of digits within a single row
+struct Number {
+    value: usize,
+    row: usize,
+    cols: Range<usize>,
+}
+
+impl Number {
+    fn contains(&self, pos: Pos) -> bool {
+        pos.row == self.row && self.cols.contains(&pos.col)
+    }
+
+    fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.cols.clone().map(|col| Pos::new(self.row, col))
+    }
+
+    fn is_adjacent(&self, schematic: &Schematic, pos: Pos) -> bool {
+        schematic
+            .neighbours8(pos)
+            .any(|neighbour| self.contains(neighbour))
+    }
+}
+
+fn is_symbol(ch: &char) -> bool {
+    !ch.is_ascii_digit() && *ch != '.'
+}
+
+fn get_numbers(schematic: &Schematic) -> Vec<Number> {
+    let mut numbers = vec![];
+
+    for (row_index, row) in schematic.rows().enumerate() {
+        let mut col = 0;
+        while col < row.len() {
+            let start = col;
+            let mut value = 0;
+            while let Some(digit) = row.get(col).and_then(|ch| ch.to_digit(10)) {
+                value = value * 10 + digit as usize;
+                col += 1;
+            }
+
+            if col > start {
+                numbers.push(Number {
+                    value,
+                    row: row_index,
+                    cols: start..col,
+                });
+            } else {
+                col += 1;
+            }
+        }
+    }
+
+    numbers
+}
+
+fn is_part_number(schematic: &Schematic, number: &Number) -> bool {
+    number.positions().any(|pos| {
+        schematic
+            .neighbours8(pos)
+            .any(|neighbour| is_symbol(&schematic[neighbour]))
+    })
+}
+
+fn get_part_numbers(schematic: &Schematic) -> PartNumbers {
+    get_numbers(schematic)
+        .iter()
+        .filter(|number| is_part_number(schematic, number))
+        .map(|number| number.value)
+        .collect()
+}
+
+// A gear is any * adjacent to exactly two numbers
+fn get_gear_ratios(schematic: &Schematic) -> GearRatios {
+    let numbers = get_numbers(schematic);
+    let mut ratios = vec![];
+
+    for (pos, _) in schematic.iter().filter(|(_, ch)| **ch == '*') {
+        let adjacent: Vec<&Number> = numbers
+            .iter()
+            .filter(|number| number.is_adjacent(schematic, pos))
+            .collect();
+
+        if let [first, second] = adjacent[..] {
+            ratios.push(first.value * second.value);
+        }
+    }
+
+    ratios
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Schematic;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Schematic, ParseError> {
+        // Every character is either a digit, a '.' or a symbol
+        Grid::parse(Self::DAY, input, "character", Some)
+    }
+
+    fn part1(schematic: &Schematic) -> usize {
+        let part_numbers = get_part_numbers(schematic);
+
+        part_numbers.iter().sum()
+    }
+
+    fn part2(schematic: &Schematic) -> usize {
+        let gear_ratios = get_gear_ratios(schematic);
+
+        gear_ratios.iter().sum()
+    }
+}
@@ -0,0 +1,7 @@
+use common::examples;
+use day3::Day3;
+
+#[test]
+fn examples() {
+    examples::check::<Day3>(concat!(env!("CARGO_MANIFEST_DIR"), "/data"));
+}
@@ -0,0 +1,129 @@
+use common::{
+    parse::{self, Line, ParseError},
+    Solution,
+};
+
+#[derive(Debug)]
+pub struct Card {
+    id: usize,
+    number_winners: usize,
+    score: usize,
+}
+
+pub type Cards = Vec<Card>;
+
+fn parse_numbers(line: &Line, numbers: &str) -> Result<Vec<usize>, ParseError> {
+    numbers
+        .split(" ")
+        .filter(|num| !num.is_empty())
+        .map(|num| line.parse("number", num))
+        .collect()
+}
+
+fn parse_line(line: &Line) -> Result<Card, ParseError> {
+    let split: Vec<&str> = line.text.split(":").collect();
+
+    let card = split.first().unwrap();
+    let id = card
+        .strip_prefix("Card")
+        .ok_or_else(|| line.invalid("card", card))?
+        .trim();
+    let id: usize = line.parse("card id", id)?;
+
+    // Cards are looked up by id when counting instances
+    if id != line.number {
+        return Err(line.invalid("card id", card));
+    }
+
+    let numbers: Vec<&str> = split
+        .get(1)
+        .ok_or_else(|| line.missing("numbers"))?
+        .split("|")
+        .collect();
+
+    let winning_numbers = parse_numbers(line, numbers[0])?;
+    let actual_numbers = parse_numbers(line, numbers.get(1).ok_or_else(|| line.missing("'|'"))?)?;
+
+    let numbers_intersection: Vec<usize> = winning_numbers
+        .iter()
+        .filter(|num| actual_numbers.contains(num))
+        .map(|num| num.clone().to_owned())
+        .collect();
+
+    let number_winners = numbers_intersection.len();
+
+    let score = match number_winners {
+        0 => 0,
+        n => 2usize.pow((n - 1).try_into().unwrap()),
+    };
+
+    Ok(Card {
+        id,
+        number_winners,
+        score,
+    })
+}
+
+fn parse_input(input: &str) -> Result<Cards, ParseError> {
+    let mut cards = vec![];
+    let lines: Vec<Line> = parse::lines(Day4::DAY, input).collect();
+
+    for line in &lines {
+        cards.push(parse_line(line)?);
+    }
+
+    // Winning cards copy the ones below them, which have to exist
+    for (card, line) in cards.iter().zip(&lines) {
+        if card.id + card.number_winners > cards.len() {
+            return Err(line.unusable("card", line.text, "wins copies past the last card"));
+        }
+    }
+
+    Ok(cards)
+}
+
+fn calculate_instances(cards: &Cards) -> Vec<usize> {
+    let mut instances = vec![1usize; cards.len()];
+
+    for card in cards {
+        let num_winners = card.number_winners;
+        // println!(
+        //     "\nCurrent card: {} - Number winners: {}\n",
+        //     card.id, card.number_winners
+        // );
+        // dbg!(&instances);
+        for add_to_card_id in card.id + 1..(card.id + num_winners + 1) {
+            // dbg!(add_to_card_id);
+            unsafe {
+                let add_num: usize = *instances.get_unchecked_mut(card.id - 1);
+                *instances.get_unchecked_mut(add_to_card_id - 1) += add_num;
+            }
+        }
+    }
+
+    instances
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Cards;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Cards, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(cards: &Cards) -> usize {
+        cards.iter().fold(0, |acc, card| acc + card.score)
+    }
+
+    fn part2(cards: &Cards) -> usize {
+        let instances = calculate_instances(cards);
+
+        instances.iter().sum()
+    }
+}
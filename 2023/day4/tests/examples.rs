@@ -0,0 +1,7 @@
+use common::examples;
+use day4::Day4;
+
+#[test]
+fn examples() {
+    examples::check::<Day4>(concat!(env!("CARGO_MANIFEST_DIR"), "/data"));
+}
@@ -0,0 +1,569 @@
+use common::{
+    parse::{self, Line, ParseError, ParseErrorKind},
+    Solution,
+};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+};
+
+mod piecewise;
+mod validate;
+
+pub use piecewise::PiecewiseMap;
+pub use validate::{validate, Gap, Validation};
+
+type Seed = usize;
+type Seeds = Vec<Seed>;
+type Ranges = Vec<RangeMap>;
+type Category = String;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Range {
+    pub min: usize,
+    pub max: usize,
+}
+
+#[derive(Debug)]
+struct RangeMap {
+    source: Range,
+    destination: Range,
+    line: usize,
+}
+
+// One `X-to-Y map:` section
+#[derive(Debug)]
+struct CategoryMap {
+    source: Category,
+    destination: Category,
+    ranges: Ranges,
+    line: usize,
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Seeds,
+    // Part 2's reading of the seeds, which only it has to reject
+    seed_ranges: Result<Vec<Range>, ParseError>,
+    maps: Vec<CategoryMap>,
+}
+
+/// Part 2's answer, or why the seeds don't pair up into ranges.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LowestLocation {
+    Found(usize),
+    BadSeedRanges(ParseError),
+}
+
+impl Range {
+    fn is_in(&self, number: usize) -> bool {
+        self.min <= number && number <= self.max
+    }
+
+    fn intersection(&self, other: &Range) -> Option<Range> {
+        let (min, max) = (self.min.max(other.min), self.max.min(other.max));
+        (min <= max).then_some(Range { min, max })
+    }
+}
+
+impl RangeMap {
+    fn get(&self, number: usize) -> usize {
+        // In source, then get it in destination; else return number
+        if self.source.is_in(number) {
+            let diff: usize = number - self.source.min;
+            self.destination.min + diff
+        } else {
+            number
+        }
+    }
+
+    // Splits `range` at the source bounds: the overlapping piece comes back mapped into the
+    // destination, the pieces either side of the source are left as they are
+    fn get_range(&self, range: &Range) -> (Option<Range>, Vec<Range>) {
+        let Some(overlap) = range.intersection(&self.source) else {
+            return (None, vec![*range]);
+        };
+
+        let mapped = Range {
+            min: self.get(overlap.min),
+            max: self.get(overlap.max),
+        };
+
+        let mut unmapped = vec![];
+        if range.min < overlap.min {
+            unmapped.push(Range {
+                min: range.min,
+                max: overlap.min - 1,
+            });
+        }
+        if overlap.max < range.max {
+            unmapped.push(Range {
+                min: overlap.max + 1,
+                max: range.max,
+            });
+        }
+
+        (Some(mapped), unmapped)
+    }
+}
+
+// Empty ranges and ones running past `usize::MAX` go into `problems` rather than the map, so
+// every one of them can be reported
+fn add_range(
+    ranges: &mut Ranges,
+    line: &Line,
+    problems: &mut Vec<ParseError>,
+) -> Result<(), ParseError> {
+    let mut split = line.text.split(" ");
+    let (destination_text, source_text, length_text) = (split.next(), split.next(), split.next());
+
+    let (destination_start, source_start, range_length): (usize, usize, usize) = (
+        line.parse_next("destination range start", destination_text)?,
+        line.parse_next("source range start", source_text)?,
+        line.parse_next("range length", length_text)?,
+    );
+    let length_text = length_text.unwrap();
+
+    if range_length == 0 {
+        problems.push(line.unusable("range length", length_text, "empty range"));
+        return Ok(());
+    }
+
+    let (Some(source_max), Some(destination_max)) = (
+        source_start.checked_add(range_length - 1),
+        destination_start.checked_add(range_length - 1),
+    ) else {
+        problems.push(line.unusable("range length", length_text, "runs past the largest number"));
+        return Ok(());
+    };
+
+    ranges.push(RangeMap {
+        source: Range {
+            min: source_start,
+            max: source_max,
+        },
+        destination: Range {
+            min: destination_start,
+            max: destination_max,
+        },
+        line: line.number,
+    });
+
+    Ok(())
+}
+
+fn get(ranges: &Ranges, index: usize) -> usize {
+    for range in ranges {
+        if range.source.is_in(index) {
+            return range.get(index);
+        }
+    }
+
+    index
+}
+
+// Interval version of `get`: values no map covers pass through unchanged
+fn get_ranges(ranges: &Ranges, input: Vec<Range>) -> Vec<Range> {
+    let mut mapped = vec![];
+    let mut unmapped = input;
+
+    for range_map in ranges {
+        let mut still_unmapped = vec![];
+        for range in &unmapped {
+            let (piece, rest) = range_map.get_range(range);
+            mapped.extend(piece);
+            still_unmapped.extend(rest);
+        }
+        unmapped = still_unmapped;
+    }
+
+    mapped.extend(unmapped);
+    mapped
+}
+
+impl Default for Almanac {
+    fn default() -> Self {
+        Almanac {
+            seeds: vec![],
+            seed_ranges: Ok(vec![]),
+            maps: vec![],
+        }
+    }
+}
+
+impl fmt::Display for LowestLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LowestLocation::Found(location) => write!(f, "{}", location),
+            LowestLocation::BadSeedRanges(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Almanac {
+    // The maps to go through, in order, to get from one category to another
+    fn path(&self, from: &str, to: &str) -> Option<Vec<&CategoryMap>> {
+        let mut came_by: HashMap<&str, usize> = HashMap::new();
+        let mut to_visit = VecDeque::from([from]);
+
+        while let Some(category) = to_visit.pop_front() {
+            if category == to {
+                let mut path = vec![];
+                let mut category = to;
+                while category != from {
+                    let map = &self.maps[came_by[category]];
+                    path.push(map);
+                    category = &map.source;
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            for (i, map) in self.maps.iter().enumerate() {
+                if map.source == category
+                    && map.destination != from
+                    && !came_by.contains_key(map.destination.as_str())
+                {
+                    came_by.insert(&map.destination, i);
+                    to_visit.push_back(&map.destination);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Maps a value of category `from` to category `to`, or `None` when `to` can't be reached.
+    pub fn get(&self, from: &str, to: &str, value: usize) -> Option<usize> {
+        let path = self.path(from, to)?;
+        Some(
+            path.iter()
+                .fold(value, |value, map| get(&map.ranges, value)),
+        )
+    }
+
+    /// Maps intervals of category `from` to the intervals they cover in category `to`.
+    pub fn get_ranges(&self, from: &str, to: &str, ranges: Vec<Range>) -> Option<Vec<Range>> {
+        let path = self.path(from, to)?;
+        Some(
+            path.iter()
+                .fold(ranges, |ranges, map| get_ranges(&map.ranges, ranges)),
+        )
+    }
+
+    /// Flattens every map between `from` and `to` into one, for O(log n) lookups.
+    pub fn compose(&self, from: &str, to: &str) -> Option<PiecewiseMap> {
+        let path = self.path(from, to)?;
+        Some(path.iter().fold(PiecewiseMap::identity(), |composed, map| {
+            composed.then(&PiecewiseMap::from_ranges(&map.ranges))
+        }))
+    }
+
+    fn location_ranges(&self, seeds: Vec<Range>) -> Vec<Range> {
+        self.get_ranges(SEED, LOCATION, seeds).unwrap()
+    }
+
+    fn location(&self, seed: Seed) -> usize {
+        self.get(SEED, LOCATION, seed).unwrap()
+    }
+}
+
+pub const SEED: &str = "seed";
+pub const LOCATION: &str = "location";
+
+fn parse_seeds(line: &Line) -> Result<Seeds, ParseError> {
+    line.text
+        .strip_prefix("seeds: ")
+        .ok_or_else(|| line.invalid("seeds", line.text))?
+        .split(" ")
+        .map(|seed| line.parse("seed", seed))
+        .collect()
+}
+
+// Part 2 reads the seeds as (start, length) pairs, so they have to pair up into non-empty ranges
+fn parse_seed_ranges(line: &Line) -> Result<Vec<Range>, ParseError> {
+    let numbers: Vec<&str> = line
+        .text
+        .strip_prefix("seeds: ")
+        .unwrap_or_default()
+        .split(" ")
+        .collect();
+
+    let mut ranges = vec![];
+    for pair in numbers.chunks(2) {
+        let [start_text, length_text] = pair else {
+            return Err(line.missing("seed range length"));
+        };
+        let (start, length): (usize, usize) = (
+            line.parse("seed", start_text)?,
+            line.parse("seed range length", length_text)?,
+        );
+
+        if length == 0 {
+            return Err(line.unusable("seed range length", length_text, "empty range"));
+        }
+        let Some(max) = start.checked_add(length - 1) else {
+            return Err(line.unusable(
+                "seed range length",
+                length_text,
+                "runs past the largest number",
+            ));
+        };
+        ranges.push(Range { min: start, max });
+    }
+
+    Ok(ranges)
+}
+
+// `X-to-Y map:`
+fn parse_map_header(line: &Line) -> Option<CategoryMap> {
+    let (source, destination) = line.text.strip_suffix(" map:")?.split_once("-to-")?;
+
+    Some(CategoryMap {
+        source: source.into(),
+        destination: destination.into(),
+        ranges: vec![],
+        line: line.number,
+    })
+}
+
+// Ranges that parse but can't be used come back alongside the almanac
+fn parse_input(input: &str) -> Result<(Almanac, Vec<ParseError>), ParseError> {
+    let lines: Vec<Line> = parse::lines(Day5::DAY, input).collect();
+
+    let Some(first) = lines.first() else {
+        return Ok((Almanac::default(), vec![]));
+    };
+    let mut almanac = Almanac {
+        seeds: parse_seeds(first)?,
+        seed_ranges: parse_seed_ranges(first),
+        maps: vec![],
+    };
+    let mut problems = vec![];
+
+    for line in &lines[1..] {
+        if line.text.is_empty() {
+            continue;
+        }
+
+        if let Some(map) = parse_map_header(line) {
+            let given = almanac
+                .maps
+                .iter()
+                .find(|other| other.source == map.source && other.destination == map.destination);
+            if let Some(given) = given {
+                return Err(line.error_at(
+                    1,
+                    ParseErrorKind::Conflict {
+                        what: "map",
+                        line: given.line,
+                    },
+                ));
+            }
+
+            almanac.maps.push(map);
+            continue;
+        }
+
+        match almanac.maps.last_mut() {
+            Some(map) => add_range(&mut map.ranges, line, &mut problems)?,
+            None => return Err(line.invalid("map header", line.text)),
+        }
+    }
+
+    Ok((almanac, problems))
+}
+
+fn get_lowest_location(almanac: &Almanac) -> usize {
+    almanac
+        .seeds
+        .iter()
+        .map(|seed| almanac.location(*seed))
+        .min()
+        .unwrap()
+}
+
+// Every mapped interval starts at its lowest location
+fn get_lowest(almanac: &Almanac) -> LowestLocation {
+    let seed_ranges = match &almanac.seed_ranges {
+        Ok(seed_ranges) => seed_ranges.clone(),
+        Err(err) => return LowestLocation::BadSeedRanges(err.clone()),
+    };
+
+    almanac
+        .location_ranges(seed_ranges)
+        .iter()
+        .map(|range| range.min)
+        .min()
+        .map_or(LowestLocation::Found(0), LowestLocation::Found)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+    type Output1 = usize;
+    type Output2 = LowestLocation;
+
+    fn parse(input: &str) -> Result<Almanac, ParseError> {
+        let (almanac, validation) = validate::check(input)?;
+
+        // Overlapping or unusable ranges would make the answers depend on range order
+        if let Some(error) = validation.errors.into_iter().next() {
+            return Err(error);
+        }
+
+        if almanac.path(SEED, LOCATION).is_none() {
+            return Err(ParseError {
+                day: Self::DAY,
+                line: input.lines().count(),
+                column: 1,
+                kind: ParseErrorKind::Missing {
+                    what: "maps leading from seed to location",
+                },
+            });
+        }
+
+        Ok(almanac)
+    }
+
+    fn part1(almanac: &Almanac) -> usize {
+        get_lowest_location(almanac)
+    }
+
+    fn part2(almanac: &Almanac) -> LowestLocation {
+        get_lowest(almanac)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interval_mapping_matches_seed_by_seed() {
+        let input = include_str!("../data/part1-test");
+        let almanac = Day5::parse(input).unwrap();
+
+        for (start, length) in [(79, 14), (55, 13), (0, 100)] {
+            let seeds = Range {
+                min: start,
+                max: start + length - 1,
+            };
+            let mut locations: Vec<usize> = almanac
+                .location_ranges(vec![seeds])
+                .iter()
+                .flat_map(|range| range.min..=range.max)
+                .collect();
+            locations.sort();
+
+            let mut expected: Vec<usize> = (start..start + length)
+                .map(|seed| almanac.location(seed))
+                .collect();
+            expected.sort();
+
+            assert_eq!(locations, expected);
+        }
+    }
+
+    #[test]
+    fn composed_map_matches_chain_and_inverts() {
+        let input = include_str!("../data/part1-test");
+        let almanac = Day5::parse(input).unwrap();
+
+        let composed = almanac.compose(SEED, LOCATION).unwrap();
+        let inverse = composed.invert().unwrap();
+
+        for seed in (0..120).chain([usize::MAX - 1, usize::MAX]) {
+            let location = almanac.location(seed);
+            assert_eq!(composed.get(seed), location);
+            assert_eq!(inverse.get(location), seed);
+        }
+    }
+
+    #[test]
+    fn validation_reports_every_bad_range() {
+        let input = "seeds: 1 5\n\n\
+                     seed-to-soil map:\n10 5 5\n20 8 2\n0 30 0\n\n\
+                     soil-to-location map:\n0 18446744073709551610 10\n";
+        let validation = validate(input).unwrap();
+
+        let errors: Vec<String> = validation.errors.iter().map(|e| e.to_string()).collect();
+        assert_eq!(
+            errors,
+            [
+                "day5: line 5, column 1: source range conflicts with line 4",
+                "day5: line 6, column 6: unusable range length '0': empty range",
+                "day5: line 9, column 24: unusable range length '10': runs past the largest number",
+            ]
+        );
+        assert_eq!(
+            validation.gaps,
+            [Gap {
+                line: 3,
+                section: "seed-to-soil".into(),
+                range: Range { min: 0, max: 4 },
+            }]
+        );
+        assert_eq!(Day5::parse(input).unwrap_err(), validation.errors[0]);
+
+        let twice = format!("{}\nseed-to-soil map:\n1 2 3\n", input);
+        assert_eq!(
+            Day5::parse(&twice).unwrap_err().to_string(),
+            "day5: line 11, column 1: map conflicts with line 3"
+        );
+    }
+
+    #[test]
+    fn seeds_have_to_pair_up_into_ranges() {
+        let input = include_str!("../data/part1-test");
+        // Part 1 reads the seeds one by one, so only part 2 turns them down
+        let error = |seeds: &str| {
+            let input = input.replacen("seeds: 79 14 55 13", seeds, 1);
+            Day5::part2(&Day5::parse(&input).unwrap()).to_string()
+        };
+
+        let odd = input.replacen("seeds: 79 14 55 13", "seeds: 79 14 55", 1);
+        assert_eq!(Day5::part1(&Day5::parse(&odd).unwrap()), 43);
+
+        assert_eq!(
+            error("seeds: 79 14 55"),
+            "day5: line 1, column 16: missing seed range length"
+        );
+        assert_eq!(
+            error("seeds: 79 0"),
+            "day5: line 1, column 11: unusable seed range length '0': empty range"
+        );
+        assert_eq!(
+            error("seeds: 0 0"),
+            "day5: line 1, column 10: unusable seed range length '0': empty range"
+        );
+        assert_eq!(
+            error("seeds: 18446744073709551615 2"),
+            "day5: line 1, column 29: unusable seed range length '2': runs past the largest number"
+        );
+    }
+
+    #[test]
+    fn follows_reordered_and_extra_categories() {
+        let input = "seeds: 1 5\n\n\
+                     water-to-light map:\n100 10 5\n\n\
+                     seed-to-water map:\n10 0 10\n\n\
+                     light-to-location map:\n0 100 5\n";
+        let almanac = Day5::parse(input).unwrap();
+
+        assert_eq!(almanac.get(SEED, LOCATION, 1), Some(1));
+        assert_eq!(almanac.get(SEED, LOCATION, 5), Some(15));
+        assert_eq!(almanac.get("water", "light", 12), Some(102));
+        assert_eq!(almanac.get("light", SEED, 0), None);
+        assert_eq!(
+            almanac.get_ranges(SEED, "light", vec![Range { min: 0, max: 9 }]),
+            Some(vec![
+                Range { min: 100, max: 104 },
+                Range { min: 15, max: 19 }
+            ])
+        );
+    }
+}
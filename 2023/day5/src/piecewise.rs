@@ -0,0 +1,166 @@
+use crate::{get, Range, Ranges};
+use std::fmt;
+
+// `source` shifted onto the values from `destination_min` up
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Piece {
+    source: Range,
+    destination_min: usize,
+}
+
+impl Piece {
+    fn get(&self, value: usize) -> usize {
+        self.destination_min + (value - self.source.min)
+    }
+
+    fn destination(&self) -> Range {
+        Range {
+            min: self.destination_min,
+            max: self.get(self.source.max),
+        }
+    }
+}
+
+/// A map over every `usize`, made of sorted, non-overlapping pieces that each shift a range of
+/// values by a constant. Any chain of almanac maps flattens into one of these.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PiecewiseMap {
+    pieces: Vec<Piece>,
+}
+
+impl PiecewiseMap {
+    pub fn identity() -> PiecewiseMap {
+        PiecewiseMap {
+            pieces: vec![Piece {
+                source: Range {
+                    min: 0,
+                    max: usize::MAX,
+                },
+                destination_min: 0,
+            }],
+        }
+    }
+
+    // Values between two source bounds all go through the same range (or none), so each of those
+    // stretches becomes a piece
+    pub(crate) fn from_ranges(ranges: &Ranges) -> PiecewiseMap {
+        let mut bounds = vec![0];
+        for range in ranges {
+            bounds.push(range.source.min);
+            if let Some(after) = range.source.max.checked_add(1) {
+                bounds.push(after);
+            }
+        }
+        bounds.sort();
+        bounds.dedup();
+
+        let pieces = bounds
+            .iter()
+            .enumerate()
+            .map(|(i, &min)| Piece {
+                source: Range {
+                    min,
+                    max: bounds.get(i + 1).map_or(usize::MAX, |next| next - 1),
+                },
+                destination_min: get(ranges, min),
+            })
+            .collect();
+
+        PiecewiseMap { pieces }.merged()
+    }
+
+    // Joins neighbouring pieces that shift by the same amount
+    fn merged(self) -> PiecewiseMap {
+        let mut pieces: Vec<Piece> = vec![];
+
+        for piece in self.pieces {
+            match pieces.last_mut() {
+                Some(last)
+                    if last.destination().max.checked_add(1) == Some(piece.destination_min) =>
+                {
+                    last.source.max = piece.source.max
+                }
+                _ => pieces.push(piece),
+            }
+        }
+
+        PiecewiseMap { pieces }
+    }
+
+    fn piece_for(&self, value: usize) -> &Piece {
+        let i = self
+            .pieces
+            .partition_point(|piece| piece.source.max < value);
+        &self.pieces[i]
+    }
+
+    /// Binary searches for the piece holding `value`.
+    pub fn get(&self, value: usize) -> usize {
+        self.piece_for(value).get(value)
+    }
+
+    /// Applies `self` and then `next` as a single map.
+    pub fn then(&self, next: &PiecewiseMap) -> PiecewiseMap {
+        let mut pieces = vec![];
+
+        for piece in &self.pieces {
+            let mut min = piece.source.min;
+            loop {
+                let value = piece.get(min);
+                let next_piece = next.piece_for(value);
+
+                // Stop at whichever piece ends first
+                let length = (next_piece.source.max - value).min(piece.source.max - min);
+                pieces.push(Piece {
+                    source: Range {
+                        min,
+                        max: min + length,
+                    },
+                    destination_min: next_piece.get(value),
+                });
+
+                if min + length == piece.source.max {
+                    break;
+                }
+                min += length + 1;
+            }
+        }
+
+        PiecewiseMap { pieces }.merged()
+    }
+
+    /// The map going the other way, if every value is reached from exactly one other.
+    pub fn invert(&self) -> Option<PiecewiseMap> {
+        let mut pieces: Vec<Piece> = self
+            .pieces
+            .iter()
+            .map(|piece| Piece {
+                source: piece.destination(),
+                destination_min: piece.source.min,
+            })
+            .collect();
+        pieces.sort_by_key(|piece| piece.source.min);
+
+        let covers_everything = pieces.first()?.source.min == 0
+            && pieces.last()?.source.max == usize::MAX
+            && pieces
+                .windows(2)
+                .all(|pair| pair[0].source.max.checked_add(1) == Some(pair[1].source.min));
+
+        covers_everything.then(|| PiecewiseMap { pieces }.merged())
+    }
+}
+
+impl fmt::Display for PiecewiseMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for piece in &self.pieces {
+            let destination = piece.destination();
+            writeln!(
+                f,
+                "{}..={} -> {}..={}",
+                piece.source.min, piece.source.max, destination.min, destination.max
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{parse_input, Almanac, CategoryMap, Day5, Range};
+use common::{
+    parse::{ParseError, ParseErrorKind},
+    Solution,
+};
+use std::fmt;
+
+/// Values below a map's highest source that none of its ranges cover, so they pass through as
+/// they are. Allowed, but worth knowing about.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gap {
+    pub line: usize,
+    pub section: String,
+    pub range: Range,
+}
+
+/// What's wrong with an almanac's maps: errors stop it being solved, gaps don't.
+#[derive(Debug, Default)]
+pub struct Validation {
+    pub errors: Vec<ParseError>,
+    pub gaps: Vec<Gap>,
+}
+
+impl fmt::Display for Gap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "day{}: line {}: {} map leaves {}..={} unmapped",
+            Day5::DAY,
+            self.line,
+            self.section,
+            self.range.min,
+            self.range.max
+        )
+    }
+}
+
+impl CategoryMap {
+    fn sorted_ranges(&self) -> Vec<(Range, usize)> {
+        let mut ranges: Vec<(Range, usize)> = self
+            .ranges
+            .iter()
+            .map(|range| (range.source, range.line))
+            .collect();
+        ranges.sort_by_key(|(source, _)| source.min);
+        ranges
+    }
+
+    // Each later line is reported against the earlier line it overlaps
+    fn overlaps(&self) -> Vec<ParseError> {
+        let mut overlaps = vec![];
+        let mut furthest: Option<(Range, usize)> = None;
+
+        for (source, line) in self.sorted_ranges() {
+            match furthest {
+                Some((other, other_line)) if source.min <= other.max => {
+                    overlaps.push(ParseError {
+                        day: Day5::DAY,
+                        line: line.max(other_line),
+                        column: 1,
+                        kind: ParseErrorKind::Conflict {
+                            what: "source range",
+                            line: line.min(other_line),
+                        },
+                    });
+                    if source.max > other.max {
+                        furthest = Some((source, line));
+                    }
+                }
+                _ => furthest = Some((source, line)),
+            }
+        }
+
+        overlaps
+    }
+
+    fn gaps(&self) -> Vec<Gap> {
+        let mut gaps = vec![];
+        let mut next = Some(0);
+
+        for (source, _) in self.sorted_ranges() {
+            let Some(min) = next else { break };
+            if source.min > min {
+                gaps.push(Gap {
+                    line: self.line,
+                    section: format!("{}-to-{}", self.source, self.destination),
+                    range: Range {
+                        min,
+                        max: source.min - 1,
+                    },
+                });
+            }
+            next = source.max.checked_add(1).map(|after| after.max(min));
+        }
+
+        gaps
+    }
+}
+
+// Parses and validates together, so solving can reuse the almanac
+pub(crate) fn check(input: &str) -> Result<(Almanac, Validation), ParseError> {
+    let (almanac, mut errors) = parse_input(input)?;
+
+    let mut gaps = vec![];
+    for map in &almanac.maps {
+        errors.extend(map.overlaps());
+        gaps.extend(map.gaps());
+    }
+    errors.sort_by_key(|error| (error.line, error.column));
+
+    Ok((almanac, Validation { errors, gaps }))
+}
+
+/// Checks every map for empty, overflowing and overlapping ranges, and for gaps between them.
+/// Input that doesn't parse at all is still a single `ParseError`.
+pub fn validate(input: &str) -> Result<Validation, ParseError> {
+    check(input).map(|(_, validation)| validation)
+}
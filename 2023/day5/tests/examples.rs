@@ -0,0 +1,7 @@
+use common::examples;
+use day5::Day5;
+
+#[test]
+fn examples() {
+    examples::check::<Day5>(concat!(env!("CARGO_MANIFEST_DIR"), "/data"));
+}
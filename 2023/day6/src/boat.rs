@@ -0,0 +1,112 @@
+use crate::{number_of_ways_to_win, Distance, Race, Time, Ways};
+
+/// How far a boat gets in a race, given how long its button is held before letting go.
+pub trait BoatModel {
+    /// Has to rise to a single peak and then fall as `hold_for` goes from 0 to `time`.
+    /// Distances too big for a u128 saturate.
+    fn distance(&self, time: Time, hold_for: Time) -> Distance;
+
+    fn ways_to_win(&self, race: &Race) -> Ways {
+        count_ways(self, race)
+    }
+}
+
+/// The puzzle's rules: every millisecond held adds 1 mm/ms of speed.
+#[derive(Clone, Copy, Debug)]
+pub struct Linear;
+
+/// Like `Linear`, but holding on past `top_speed` doesn't make the boat any faster.
+#[derive(Clone, Copy, Debug)]
+pub struct Capped {
+    pub top_speed: Time,
+}
+
+/// Speed grows with the square of the time held.
+#[derive(Clone, Copy, Debug)]
+pub struct Quadratic;
+
+/// Like `Linear`, but the boat loses `decay` mm/ms every millisecond it moves, until it stops.
+#[derive(Clone, Copy, Debug)]
+pub struct Decaying {
+    pub decay: Time,
+}
+
+impl BoatModel for Linear {
+    fn distance(&self, time: Time, hold_for: Time) -> Distance {
+        hold_for.saturating_mul(time - hold_for)
+    }
+
+    // Solved exactly, no search needed
+    fn ways_to_win(&self, race: &Race) -> Ways {
+        number_of_ways_to_win(&race.time, &race.distance)
+    }
+}
+
+impl BoatModel for Capped {
+    fn distance(&self, time: Time, hold_for: Time) -> Distance {
+        hold_for.min(self.top_speed).saturating_mul(time - hold_for)
+    }
+}
+
+impl BoatModel for Quadratic {
+    fn distance(&self, time: Time, hold_for: Time) -> Distance {
+        hold_for
+            .saturating_mul(hold_for)
+            .saturating_mul(time - hold_for)
+    }
+}
+
+impl BoatModel for Decaying {
+    fn distance(&self, time: Time, hold_for: Time) -> Distance {
+        let moving_time = time - hold_for;
+        if self.decay == 0 {
+            return hold_for.saturating_mul(moving_time);
+        }
+
+        // Moves at hold_for, hold_for - decay, ... for as long as either the race or the speed lasts
+        let moves = moving_time.min(hold_for.div_ceil(self.decay));
+        let lost = moves
+            .checked_mul(moves.saturating_sub(1))
+            .and_then(|pairs| (pairs / 2).checked_mul(self.decay));
+
+        moves
+            .checked_mul(hold_for)
+            .zip(lost)
+            .map_or(Distance::MAX, |(top, lost)| top - lost)
+    }
+}
+
+// The first value in `low..=high` that `is_past`, which has to hold for `high` and keep holding
+// once it does
+pub(crate) fn first(mut low: Time, mut high: Time, is_past: impl Fn(Time) -> bool) -> Time {
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if is_past(middle) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+    low
+}
+
+/// Counts the winning hold times of any model by binary searching for the curve's peak, then for
+/// where each side of it crosses the record.
+pub fn count_ways<M: BoatModel + ?Sized>(model: &M, race: &Race) -> Ways {
+    let (time, record) = (race.time, race.distance);
+    let distance = |hold_for| model.distance(time, hold_for);
+
+    let peak = first(0, time, |hold_for| {
+        hold_for == time || distance(hold_for) >= distance(hold_for + 1)
+    });
+    if distance(peak) <= record {
+        return 0;
+    }
+
+    let first_win = first(0, peak, |hold_for| distance(hold_for) > record);
+    let last_win = first(peak, time, |hold_for| {
+        hold_for == time || distance(hold_for + 1) <= record
+    });
+
+    last_win - first_win + 1
+}
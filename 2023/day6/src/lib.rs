@@ -0,0 +1,260 @@
+use common::{
+    parse::{self, Line, ParseError},
+    Solution,
+};
+use std::iter::zip;
+
+mod boat;
+
+pub use boat::{count_ways, BoatModel, Capped, Decaying, Linear, Quadratic};
+
+pub type Time = u128;
+pub type Distance = u128;
+type Ways = u128;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Race {
+    pub time: Time,
+    pub distance: Distance,
+}
+
+/// How to read the sheet: as a race per column, or with bad kerning, where all the numbers on a
+/// line are really one number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reading {
+    Separate,
+    Kerned,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Races {
+    pub races: Vec<Race>,
+    pub kerned: Race,
+}
+
+// Returns the numbers part of the `prefix` line, e.g. "  7  15   30" for "Time:"
+fn numbers_of<'a>(
+    lines: &[Line<'a>],
+    index: usize,
+    prefix: &'static str,
+) -> Result<(Line<'a>, &'a str), ParseError> {
+    let line = lines.get(index).copied().unwrap_or(Line {
+        day: Day6::DAY,
+        number: index + 1,
+        text: "",
+    });
+    let numbers = line
+        .text
+        .strip_prefix(prefix)
+        .ok_or_else(|| line.missing(prefix))?;
+
+    Ok((line, numbers))
+}
+
+// Each number on the line, and all of them read as one number
+fn parse_numbers(
+    lines: &[Line],
+    index: usize,
+    prefix: &'static str,
+    what: &'static str,
+) -> Result<(Vec<u128>, u128), ParseError> {
+    let (line, numbers) = numbers_of(lines, index, prefix)?;
+
+    let separate = numbers
+        .split_whitespace()
+        .map(|num| line.parse(what, num))
+        .collect::<Result<_, _>>()?;
+    let kerned = numbers
+        .replace(" ", "")
+        .parse()
+        .map_err(|_| line.invalid(what, numbers))?;
+
+    Ok((separate, kerned))
+}
+
+fn parse_input(lines: &[Line]) -> Result<Races, ParseError> {
+    let (times, kerned_time) = parse_numbers(lines, 0, "Time:", "time")?;
+    let (distances, kerned_distance) = parse_numbers(lines, 1, "Distance:", "distance")?;
+
+    if times.len() != distances.len() {
+        let (line, numbers) = numbers_of(lines, 1, "Distance:")?;
+        return Err(line.invalid("number of distances", numbers));
+    }
+
+    Ok(Races {
+        races: zip(times, distances)
+            .map(|(time, distance)| Race { time, distance })
+            .collect(),
+        kerned: Race {
+            time: kerned_time,
+            distance: kerned_distance,
+        },
+    })
+}
+
+// Holding for `hold_for` leaves `time - hold_for` to move at speed `hold_for`. A product too big
+// for a u128 certainly beats the record.
+fn beats(time: Time, distance: Distance, hold_for: Time) -> bool {
+    hold_for
+        .checked_mul(time - hold_for)
+        .is_none_or(|travelled| travelled > distance)
+}
+
+// Winning hold times h solve h * (time - h) > distance, i.e. they lie strictly between the roots
+// (time ± sqrt(time² - 4 * distance)) / 2. The integer square root can land a step off the real
+// root, and when the root is exact it only ties the record, so the estimate is nudged onto the
+// first hold time that really wins. The winners are symmetric around time / 2.
+fn number_of_ways_to_win(time: &Time, distance: &Distance) -> Ways {
+    let (time, distance) = (*time, *distance);
+
+    // The best anyone can do is holding for half the race
+    if !beats(time, distance, time / 2) {
+        return 0;
+    }
+
+    let discriminant = time
+        .checked_mul(time)
+        .zip(distance.checked_mul(4))
+        .map(|(squared, record)| squared - record);
+
+    let mut first = match discriminant {
+        Some(discriminant) => (time - discriminant.isqrt()) / 2,
+        // Too big to square: binary search the rising half of the curve instead
+        None => boat::first(0, time / 2, |hold_for| beats(time, distance, hold_for)),
+    };
+    while !beats(time, distance, first) {
+        first += 1;
+    }
+    while first > 0 && beats(time, distance, first - 1) {
+        first -= 1;
+    }
+
+    time - 2 * first + 1
+}
+
+impl Race {
+    pub fn ways_to_win(&self, model: &dyn BoatModel) -> Ways {
+        model.ways_to_win(self)
+    }
+}
+
+impl Races {
+    /// Separate races multiply their ways to win together; kerned, there's only the one race.
+    pub fn ways_to_win(&self, reading: Reading, model: &dyn BoatModel) -> Ways {
+        match reading {
+            Reading::Separate => self
+                .races
+                .iter()
+                .map(|race| race.ways_to_win(model))
+                .product(),
+            Reading::Kerned => self.kerned.ways_to_win(model),
+        }
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Races;
+    type Output1 = Ways;
+    type Output2 = Ways;
+
+    fn parse(input: &str) -> Result<Races, ParseError> {
+        let lines: Vec<Line> = parse::lines(Self::DAY, input).collect();
+
+        parse_input(&lines)
+    }
+
+    fn part1(races: &Races) -> Ways {
+        races.ways_to_win(Reading::Separate, &Linear)
+    }
+
+    fn part2(races: &Races) -> Ways {
+        races.ways_to_win(Reading::Kerned, &Linear)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The original approach: try every hold time
+    fn number_of_ways_to_win_by_trying(time: Time, distance: Distance) -> Ways {
+        (0..=time)
+            .filter(|hold_for| hold_for * (time - hold_for) > distance)
+            .count() as Ways
+    }
+
+    #[test]
+    fn closed_form_matches_trying_every_hold_time() {
+        for time in 0..60 {
+            // Include every exact root, where the best hold times only tie the record
+            for distance in 0..=time * time / 4 + 1 {
+                assert_eq!(
+                    number_of_ways_to_win(&time, &distance),
+                    number_of_ways_to_win_by_trying(time, distance),
+                    "time {time}, distance {distance}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn searching_matches_trying_every_hold_time() {
+        let models: [&dyn BoatModel; 7] = [
+            &Linear,
+            &Capped { top_speed: 0 },
+            &Capped { top_speed: 7 },
+            &Quadratic,
+            &Decaying { decay: 0 },
+            &Decaying { decay: 1 },
+            &Decaying { decay: 3 },
+        ];
+
+        for (i, model) in models.into_iter().enumerate() {
+            for time in 0..40 {
+                let best = (0..=time).map(|h| model.distance(time, h)).max().unwrap();
+                for distance in 0..=best + 1 {
+                    let race = Race { time, distance };
+                    let tried = (0..=time)
+                        .filter(|&h| model.distance(time, h) > distance)
+                        .count() as Ways;
+
+                    assert_eq!(count_ways(model, &race), tried, "model {i}, {race:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reads_races_both_ways() {
+        let races = Day6::parse("Time:      7  15   30\nDistance:  9  40  200\n").unwrap();
+
+        assert_eq!(
+            races.races[1],
+            Race {
+                time: 15,
+                distance: 40
+            }
+        );
+        assert_eq!(
+            races.kerned,
+            Race {
+                time: 71530,
+                distance: 940200
+            }
+        );
+        assert_eq!(races.ways_to_win(Reading::Separate, &Linear), 288);
+        assert_eq!(races.ways_to_win(Reading::Kerned, &Linear), 71503);
+    }
+
+    #[test]
+    fn handles_races_too_long_to_square() {
+        let time = u128::MAX / 2;
+
+        assert_eq!(number_of_ways_to_win(&time, &0), time - 1);
+        assert_eq!(number_of_ways_to_win(&time, &(time - 1)), time - 3);
+    }
+}
@@ -0,0 +1,7 @@
+use common::examples;
+use day6::Day6;
+
+#[test]
+fn examples() {
+    examples::check::<Day6>(concat!(env!("CARGO_MANIFEST_DIR"), "/data"));
+}
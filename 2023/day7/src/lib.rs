@@ -0,0 +1,378 @@
+use common::{
+    parse::{self, Line, ParseError},
+    Solution,
+};
+use std::{fmt, str::FromStr};
+
+mod report;
+mod rules;
+
+pub use report::{Report, Row};
+pub use rules::{Rules, RulesError, Rung, WildRank};
+
+type Bid = usize;
+
+pub type Cards = Vec<Card>;
+pub type Hands = Vec<(Cards, Bid)>;
+
+// Declared weakest first, so the derived order ranks them
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Card {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+/// The standard ladder's hand types. Rules can name their own.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+// Fields in comparison order: the index of the rung reached on the rules' ladder first, then
+// card by card. Built by `Rules::hand`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hand {
+    rung: usize,
+    ranks: Vec<usize>,
+    hand_type: Option<HandType>,
+    cards: Cards,
+}
+
+/// Why a hand didn't parse: the index of a card that isn't one, or a hand of the wrong size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HandError {
+    InvalidCard(usize),
+    Size(usize),
+}
+
+impl Card {
+    pub const ALL: [Card; 13] = [
+        Card::Two,
+        Card::Three,
+        Card::Four,
+        Card::Five,
+        Card::Six,
+        Card::Seven,
+        Card::Eight,
+        Card::Nine,
+        Card::Ten,
+        Card::Jack,
+        Card::Queen,
+        Card::King,
+        Card::Ace,
+    ];
+
+    fn from_char(card: char) -> Option<Card> {
+        match card {
+            'A' => Some(Card::Ace),
+            'K' => Some(Card::King),
+            'Q' => Some(Card::Queen),
+            'J' => Some(Card::Jack),
+            'T' => Some(Card::Ten),
+            '9' => Some(Card::Nine),
+            '8' => Some(Card::Eight),
+            '7' => Some(Card::Seven),
+            '6' => Some(Card::Six),
+            '5' => Some(Card::Five),
+            '4' => Some(Card::Four),
+            '3' => Some(Card::Three),
+            '2' => Some(Card::Two),
+            _ => None,
+        }
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            Card::Ace => 'A',
+            Card::King => 'K',
+            Card::Queen => 'Q',
+            Card::Jack => 'J',
+            Card::Ten => 'T',
+            Card::Nine => '9',
+            Card::Eight => '8',
+            Card::Seven => '7',
+            Card::Six => '6',
+            Card::Five => '5',
+            Card::Four => '4',
+            Card::Three => '3',
+            Card::Two => '2',
+        }
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+impl fmt::Display for HandType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            HandType::HighCard => "high card",
+            HandType::OnePair => "one pair",
+            HandType::TwoPair => "two pair",
+            HandType::ThreeOfAKind => "three of a kind",
+            HandType::FullHouse => "full house",
+            HandType::FourOfAKind => "four of a kind",
+            HandType::FiveOfAKind => "five of a kind",
+        };
+        f.pad(name)
+    }
+}
+
+impl Hand {
+    /// Where on its rules' ladder the hand is, weakest first. `Rules::rung` looks the rung up.
+    pub fn rung(&self) -> usize {
+        self.rung
+    }
+
+    /// The hand's type, when it was dealt under a ladder of standard hand types.
+    pub fn standard_type(&self) -> Option<HandType> {
+        self.hand_type
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+}
+
+// The index of the first character that isn't a card on failure
+fn parse_cards(hand: &str) -> Result<Cards, usize> {
+    hand.char_indices()
+        .map(|(i, card)| Card::from_char(card).ok_or(i))
+        .collect()
+}
+
+// Read under the standard rules
+impl FromStr for Hand {
+    type Err = HandError;
+
+    fn from_str(hand: &str) -> Result<Hand, HandError> {
+        let cards = parse_cards(hand).map_err(HandError::InvalidCard)?;
+        Rules::standard().hand(cards)
+    }
+}
+
+fn cards_to_string(cards: &[Card]) -> String {
+    cards.iter().map(|card| card.to_char()).collect()
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(&cards_to_string(&self.cards))
+    }
+}
+
+fn parse_hand(line: &Line, hand: &str, rules: &Rules) -> Result<Cards, ParseError> {
+    let cards = parse_cards(hand).map_err(|i| {
+        let len = hand[i..].chars().next().map_or(1, char::len_utf8);
+        line.invalid("card", &hand[i..i + len])
+    })?;
+
+    if cards.len() != rules.hand_size() {
+        return Err(line.invalid("hand", hand));
+    }
+
+    Ok(cards)
+}
+
+/// Reads hands of the size `rules` deals, to be ranked under any rules with that size.
+pub fn parse_hands(input: &str, rules: &Rules) -> Result<Hands, ParseError> {
+    let mut hands: Hands = vec![];
+
+    for line in parse::lines(Day7::DAY, input) {
+        let mut split = line.text.split_whitespace();
+        let hand = split.next().ok_or_else(|| line.missing("hand"))?;
+        let (cards, bid): (Cards, Bid) = (
+            parse_hand(&line, hand, rules)?,
+            line.parse_next("bid", split.next())?,
+        );
+        hands.push((cards, bid));
+    }
+
+    Ok(hands)
+}
+
+/// Weakest hand first, so a hand's rank is its index plus one. `hands` have to be the size
+/// `rules` deals, as `parse_hands` makes sure of.
+pub fn ranked(hands: &Hands, rules: &Rules) -> Vec<(Hand, Bid)> {
+    let mut hands: Vec<(Hand, Bid)> = hands
+        .iter()
+        .map(|(cards, bid)| (rules.hand(cards.clone()).unwrap(), *bid))
+        .collect();
+    hands.sort_by(|(first, _), (second, _)| first.cmp(second));
+
+    hands
+}
+
+pub fn total_winnings(hands: &Hands, rules: &Rules) -> usize {
+    ranked(hands, rules)
+        .iter()
+        .enumerate()
+        .fold(0, |sum, (i, (_, bid))| sum + (i + 1) * bid)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Hands;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Hands, ParseError> {
+        parse_hands(input, &Rules::standard())
+    }
+
+    fn part1(hands: &Hands) -> usize {
+        total_winnings(hands, &Rules::standard())
+    }
+
+    fn part2(hands: &Hands) -> usize {
+        total_winnings(hands, &Rules::jokers())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hands_round_trip_and_order() {
+        let mut hands: Vec<Hand> = ["32T3K", "T55J5", "KK677", "KTJJT", "QQQJA"]
+            .iter()
+            .map(|hand| hand.parse().unwrap())
+            .collect();
+        hands.sort();
+
+        let sorted: Vec<String> = hands.iter().map(Hand::to_string).collect();
+        assert_eq!(sorted, ["32T3K", "KTJJT", "KK677", "T55J5", "QQQJA"]);
+        assert_eq!(hands[1].standard_type(), Some(HandType::TwoPair));
+        assert_eq!(
+            Rules::jokers()
+                .hand(hands[1].cards().to_vec())
+                .unwrap()
+                .standard_type(),
+            Some(HandType::FourOfAKind)
+        );
+
+        assert_eq!("32X3K".parse::<Hand>(), Err(HandError::InvalidCard(2)));
+        assert_eq!("32T3".parse::<Hand>(), Err(HandError::Size(4)));
+    }
+
+    #[test]
+    fn wild_cards_promote_to_the_best_rung() {
+        let jokers = Rules::jokers();
+        let hand = |cards: &str| jokers.hand(parse_cards(cards).unwrap()).unwrap();
+
+        assert_eq!(hand("JJJJJ").standard_type(), Some(HandType::FiveOfAKind));
+        assert_eq!(hand("2JJ34").standard_type(), Some(HandType::ThreeOfAKind));
+        assert_eq!(hand("22J33").standard_type(), Some(HandType::FullHouse));
+        assert_eq!(hand("2345J").standard_type(), Some(HandType::OnePair));
+        // Jokers are weakest in a tie
+        assert!(hand("J2222") < hand("22222"));
+
+        // Three-card hands where queens are wild but keep their rank, and two pair can't happen
+        let queens = Rules::new(
+            vec![Card::Queen],
+            WildRank::Own,
+            3,
+            vec![
+                Rung::new("high card", vec![1]),
+                Rung::new("one pair", vec![2]),
+                Rung::new("three of a kind", vec![3]),
+            ],
+        )
+        .unwrap();
+        let hand = |cards: &str| queens.hand(parse_cards(cards).unwrap()).unwrap();
+
+        assert_eq!(hand("Q23").rung(), 1);
+        assert_eq!(queens.rung(&hand("QQ2")).name, "three of a kind");
+        assert_eq!(hand("QQ2").standard_type(), None);
+        assert!(hand("Q22") > hand("K22"));
+        assert_eq!(
+            queens.hand(parse_cards("2345").unwrap()),
+            Err(HandError::Size(4))
+        );
+    }
+
+    #[test]
+    fn custom_ladders_name_their_own_types() {
+        let sixes = Rules::new(
+            vec![Card::Two],
+            WildRank::Own,
+            6,
+            vec![
+                Rung::new("junk", vec![1]),
+                Rung::new("three pair", vec![2, 2, 2]),
+                Rung::new("six of a kind", vec![6]),
+            ],
+        )
+        .unwrap();
+        let hand_type = |cards: &str| {
+            let hand = sixes.hand(parse_cards(cards).unwrap()).unwrap();
+            sixes.rung(&hand).name.clone()
+        };
+
+        assert_eq!(hand_type("AAKK2Q"), "three pair");
+        assert_eq!(hand_type("AAA222"), "six of a kind");
+        assert_eq!(hand_type("AAKKQQ"), "three pair");
+        // Two triples hold no third pair
+        assert_eq!(hand_type("AAAKKK"), "junk");
+        assert_eq!(hand_type("AAAKQT"), "junk");
+
+        let rules = |hand_size, ladder| Rules::new(vec![], WildRank::Own, hand_size, ladder);
+        assert_eq!(rules(5, vec![]).unwrap_err(), RulesError::NoRungs);
+        assert_eq!(
+            rules(0, vec![Rung::new("junk", vec![1])]).unwrap_err(),
+            RulesError::HandSize
+        );
+        for needs in [vec![], vec![0], vec![2, 3], vec![4, 2]] {
+            assert_eq!(
+                rules(5, vec![Rung::new("odd", needs)]).unwrap_err(),
+                RulesError::Rung("odd".into())
+            );
+        }
+    }
+
+    #[test]
+    fn report_shows_what_jokers_were_played_as() {
+        let input = include_str!("../data/part1-test");
+        let hands = Day7::parse(input).unwrap();
+        let report = Report::new(&hands, &Rules::jokers());
+
+        let last = report.rows.last().unwrap();
+        assert_eq!((last.hand.to_string(), last.rank), ("KTJJT".into(), 5));
+        assert_eq!(cards_to_string(&last.played_as), "KTTTT");
+        assert_eq!(report.total_winnings(), Day7::part2(&hands));
+        assert!(report
+            .csv()
+            .contains("5,KTJJT,four of a kind,KTTTT,220,1100\n\ntype,count\n"));
+        let (rung, count) = &report.histogram[report.histogram.len() - 2];
+        assert_eq!((rung.hand_type, *count), (Some(HandType::FourOfAKind), 3));
+
+        assert_eq!(
+            cards_to_string(&Rules::jokers().played_as(&parse_cards("JJJJJ").unwrap())),
+            "AAAAA"
+        );
+    }
+}
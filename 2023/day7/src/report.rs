@@ -0,0 +1,134 @@
+use crate::{cards_to_string, ranked, Bid, Cards, Hand, Hands, Rules, Rung};
+use std::fmt::Write;
+
+const BAR_WIDTH: usize = 40;
+
+/// A hand's place in the ranking, and the cards its wild cards were played as.
+#[derive(Clone, Debug)]
+pub struct Row {
+    pub rank: usize,
+    pub hand: Hand,
+    pub played_as: Cards,
+    pub bid: Bid,
+}
+
+/// Every hand in rank order, and how many hands reached each rung of the ladder, weakest rung
+/// first so a hand's `rung()` indexes it.
+#[derive(Clone, Debug)]
+pub struct Report {
+    pub rows: Vec<Row>,
+    pub histogram: Vec<(Rung, usize)>,
+}
+
+impl Row {
+    pub fn winnings(&self) -> usize {
+        self.rank * self.bid
+    }
+}
+
+impl Report {
+    pub fn new(hands: &Hands, rules: &Rules) -> Report {
+        let rows: Vec<Row> = ranked(hands, rules)
+            .into_iter()
+            .enumerate()
+            .map(|(i, (hand, bid))| Row {
+                rank: i + 1,
+                played_as: rules.played_as(hand.cards()),
+                hand,
+                bid,
+            })
+            .collect();
+
+        let mut histogram: Vec<(Rung, usize)> = rules
+            .ladder()
+            .iter()
+            .map(|rung| (rung.clone(), 0))
+            .collect();
+        for row in &rows {
+            histogram[row.hand.rung()].1 += 1;
+        }
+
+        Report { rows, histogram }
+    }
+
+    pub fn total_winnings(&self) -> usize {
+        self.rows.iter().map(Row::winnings).sum()
+    }
+
+    fn type_name(&self, row: &Row) -> &str {
+        &self.histogram[row.hand.rung()].0.name
+    }
+
+    /// The ranking, then the histogram.
+    pub fn text(&self) -> String {
+        format!("{}\n{}", self.ranking_text(), self.histogram_text())
+    }
+
+    /// The ranking and the histogram as two tables, separated by a blank line.
+    pub fn csv(&self) -> String {
+        format!("{}\n{}", self.ranking_csv(), self.histogram_csv())
+    }
+
+    fn ranking_text(&self) -> String {
+        let mut text = format!(
+            "{:>5}  {:<9}  {:<15}  {:<9}  {:>5}  {:>9}\n",
+            "rank", "hand", "type", "played as", "bid", "winnings"
+        );
+        for row in &self.rows {
+            writeln!(
+                text,
+                "{:>5}  {:<9}  {:<15}  {:<9}  {:>5}  {:>9}",
+                row.rank,
+                row.hand,
+                self.type_name(row),
+                cards_to_string(&row.played_as),
+                row.bid,
+                row.winnings()
+            )
+            .unwrap();
+        }
+        writeln!(text, "Total winnings: {}", self.total_winnings()).unwrap();
+
+        text
+    }
+
+    fn ranking_csv(&self) -> String {
+        let mut csv = String::from("rank,hand,type,played_as,bid,winnings\n");
+        for row in &self.rows {
+            writeln!(
+                csv,
+                "{},{},{},{},{},{}",
+                row.rank,
+                row.hand,
+                self.type_name(row),
+                cards_to_string(&row.played_as),
+                row.bid,
+                row.winnings()
+            )
+            .unwrap();
+        }
+
+        csv
+    }
+
+    // Bars are scaled to the most common type
+    fn histogram_text(&self) -> String {
+        let most = self.histogram.iter().map(|(_, count)| *count).max();
+        let mut text = String::new();
+        for (rung, count) in &self.histogram {
+            let bar = (count * BAR_WIDTH).div_ceil(most.unwrap_or(1).max(1));
+            writeln!(text, "{:<15}  {:>5}  {}", rung.name, count, "#".repeat(bar)).unwrap();
+        }
+
+        text
+    }
+
+    fn histogram_csv(&self) -> String {
+        let mut csv = String::from("type,count\n");
+        for (rung, count) in &self.histogram {
+            writeln!(csv, "{},{}", rung.name, count).unwrap();
+        }
+
+        csv
+    }
+}
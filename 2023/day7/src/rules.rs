@@ -0,0 +1,241 @@
+use crate::{Card, Cards, Hand, HandError, HandType};
+use std::{fmt, iter::repeat_n};
+
+/// How a wild card ranks when two hands of the same type are compared card by card.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WildRank {
+    Weakest,
+    Own,
+}
+
+/// One hand type on a ladder: what it's called and the group sizes it needs, largest first. A
+/// full house is `[3, 2]`, a pair `[2]`. Rungs of the standard ladder keep their `HandType`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rung {
+    pub name: String,
+    pub needs: Vec<usize>,
+    pub hand_type: Option<HandType>,
+}
+
+/// Everything that can differ between games of Camel Cards. Built with `Rules::new`, which makes
+/// sure the ladder can classify every hand.
+#[derive(Clone, Debug)]
+pub struct Rules {
+    // Cards that stand in for whichever card makes the best hand
+    wild: Vec<Card>,
+    wild_rank: WildRank,
+    hand_size: usize,
+    // Weakest first
+    ladder: Vec<Rung>,
+}
+
+/// Why a set of rules was turned down.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RulesError {
+    HandSize,
+    NoRungs,
+    /// A rung whose needs are empty, include an empty group, aren't largest first or don't fit in
+    /// a hand
+    Rung(String),
+}
+
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RulesError::HandSize => write!(f, "hands need at least one card"),
+            RulesError::NoRungs => write!(f, "the ladder has no hand types"),
+            RulesError::Rung(name) => write!(f, "{} can't be dealt", name),
+        }
+    }
+}
+
+impl std::error::Error for RulesError {}
+
+impl Rung {
+    pub fn new(name: impl Into<String>, needs: Vec<usize>) -> Rung {
+        Rung {
+            name: name.into(),
+            needs,
+            hand_type: None,
+        }
+    }
+
+    pub fn standard(hand_type: HandType, needs: Vec<usize>) -> Rung {
+        Rung {
+            hand_type: Some(hand_type),
+            ..Rung::new(hand_type.to_string(), needs)
+        }
+    }
+}
+
+impl Rules {
+    /// `ladder` lists the hand types from weakest to strongest. A hand that reaches none of them
+    /// counts as the weakest.
+    pub fn new(
+        wild: Vec<Card>,
+        wild_rank: WildRank,
+        hand_size: usize,
+        ladder: Vec<Rung>,
+    ) -> Result<Rules, RulesError> {
+        if hand_size == 0 {
+            return Err(RulesError::HandSize);
+        }
+        if ladder.is_empty() {
+            return Err(RulesError::NoRungs);
+        }
+        for rung in &ladder {
+            let dealable = !rung.needs.is_empty()
+                && !rung.needs.contains(&0)
+                && rung.needs.is_sorted_by(|first, second| first >= second)
+                && rung.needs.iter().sum::<usize>() <= hand_size;
+            if !dealable {
+                return Err(RulesError::Rung(rung.name.clone()));
+            }
+        }
+
+        Ok(Rules {
+            wild,
+            wild_rank,
+            hand_size,
+            ladder,
+        })
+    }
+
+    /// Part 1: no wild cards, five cards a hand.
+    pub fn standard() -> Rules {
+        let ladder = [
+            (HandType::HighCard, vec![1]),
+            (HandType::OnePair, vec![2]),
+            (HandType::TwoPair, vec![2, 2]),
+            (HandType::ThreeOfAKind, vec![3]),
+            (HandType::FullHouse, vec![3, 2]),
+            (HandType::FourOfAKind, vec![4]),
+            (HandType::FiveOfAKind, vec![5]),
+        ]
+        .into_iter()
+        .map(|(hand_type, needs)| Rung::standard(hand_type, needs))
+        .collect();
+
+        Rules::new(vec![], WildRank::Own, 5, ladder).unwrap()
+    }
+
+    /// Part 2: J cards are jokers, wild but the weakest card on their own.
+    pub fn jokers() -> Rules {
+        Rules {
+            wild: vec![Card::Jack],
+            wild_rank: WildRank::Weakest,
+            ..Rules::standard()
+        }
+    }
+
+    pub fn hand_size(&self) -> usize {
+        self.hand_size
+    }
+
+    pub fn ladder(&self) -> &[Rung] {
+        &self.ladder
+    }
+
+    /// The rung `hand` reached, which has to have been dealt under these rules.
+    pub fn rung(&self, hand: &Hand) -> &Rung {
+        &self.ladder[hand.rung]
+    }
+
+    pub fn is_wild(&self, card: Card) -> bool {
+        self.wild.contains(&card)
+    }
+
+    // Wild cards ranked weakest sit below the two
+    fn rank(&self, card: Card) -> usize {
+        match self.wild_rank {
+            WildRank::Weakest if self.is_wild(card) => 0,
+            _ => card as usize + 1,
+        }
+    }
+
+    // Groups of matching natural cards as (size, card), largest and then strongest first, and how
+    // many cards are wild
+    fn groups(&self, cards: &[Card]) -> (Vec<(usize, Card)>, usize) {
+        let mut natural: Vec<Card> = cards
+            .iter()
+            .copied()
+            .filter(|card| !self.is_wild(*card))
+            .collect();
+        natural.sort();
+
+        let mut groups: Vec<(usize, Card)> = natural
+            .chunk_by(|first, second| first == second)
+            .map(|group| (group.len(), group[0]))
+            .collect();
+        groups.sort_by(|first, second| second.cmp(first));
+
+        (groups, cards.len() - natural.len())
+    }
+
+    /// The strongest rung of the ladder the cards reach, with wild cards topping up whichever
+    /// groups fall short of it. Falls back on the weakest rung.
+    pub fn classify(&self, cards: &[Card]) -> usize {
+        let (groups, wild) = self.groups(cards);
+
+        // Pairing the largest groups with the largest needs leaves the fewest to fill
+        let reaches = |needs: &[usize]| {
+            let short: usize = needs
+                .iter()
+                .enumerate()
+                .map(|(i, need)| need.saturating_sub(groups.get(i).map_or(0, |group| group.0)))
+                .sum();
+            short <= wild
+        };
+
+        self.ladder
+            .iter()
+            .rposition(|rung| reaches(&rung.needs))
+            .unwrap_or(0)
+    }
+
+    /// The cards as played, each wild card replaced by the card it stands in for: the groups the
+    /// best rung needs are topped up first, and any wild cards left over join the largest group.
+    pub fn played_as(&self, cards: &[Card]) -> Cards {
+        let (mut groups, wild) = self.groups(cards);
+        let needs = &self.ladder[self.classify(cards)].needs;
+
+        // Wild cards can make up a group of their own, of the strongest card not in one yet
+        let mut spare: Vec<Card> = Card::ALL
+            .into_iter()
+            .filter(|card| !self.is_wild(*card) && !groups.iter().any(|group| group.1 == *card))
+            .collect();
+        while groups.len() < needs.len().max(1) {
+            groups.push((0, spare.pop().unwrap_or(Card::Ace)));
+        }
+
+        let mut substitutes: Vec<Card> = needs
+            .iter()
+            .zip(&groups)
+            .flat_map(|(need, (have, card))| repeat_n(*card, need.saturating_sub(*have)))
+            .collect();
+        substitutes.resize(wild, groups[0].1);
+
+        let mut substitutes = substitutes.into_iter();
+        cards
+            .iter()
+            .map(|card| match self.is_wild(*card) {
+                true => substitutes.next().unwrap(),
+                false => *card,
+            })
+            .collect()
+    }
+
+    pub fn hand(&self, cards: Cards) -> Result<Hand, HandError> {
+        if cards.len() != self.hand_size {
+            return Err(HandError::Size(cards.len()));
+        }
+
+        let rung = self.classify(&cards);
+        Ok(Hand {
+            rung,
+            ranks: cards.iter().map(|card| self.rank(*card)).collect(),
+            hand_type: self.ladder[rung].hand_type,
+            cards,
+        })
+    }
+}
@@ -0,0 +1,7 @@
+use common::examples;
+use day7::Day7;
+
+#[test]
+fn examples() {
+    examples::check::<Day7>(concat!(env!("CARGO_MANIFEST_DIR"), "/data"));
+}
@@ -0,0 +1,194 @@
+use crate::{Network, NodeId};
+use num::integer::Integer;
+use std::{collections::BTreeSet, fmt};
+
+/// Where one ghost lands on goal nodes. Its walk takes `tail` steps to reach a loop of `length`
+/// steps, which it then goes round forever.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub tail: usize,
+    pub length: usize,
+    /// Goals reached before the loop starts
+    pub tail_hits: Vec<usize>,
+    /// Goals reached on the first time round the loop, within `tail..tail + length`
+    pub hits: Vec<usize>,
+}
+
+/// How many steps it takes for every ghost to stand on a goal at once. `Overflow` when that's
+/// more than a usize can count.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Steps {
+    After(usize),
+    Never,
+    Overflow,
+}
+
+impl fmt::Display for Steps {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Steps::After(steps) => write!(f, "{}", steps),
+            Steps::Never => write!(f, "never"),
+            Steps::Overflow => write!(f, "too many to count"),
+        }
+    }
+}
+
+impl Cycle {
+    // A state is a node together with the position in the directions, so the walk has to repeat
+    // once one comes round again. Whole passes through the directions are found first with the
+    // network's jump table, which pins the loop's length down to a number of passes, then the
+    // pass before the loop is stepped through to find exactly where it starts.
+    pub fn find(network: &Network, start: NodeId, goals: &[bool]) -> Cycle {
+        let pass = network.directions.len();
+
+        let mut started_pass: Vec<Option<usize>> = vec![None; network.len()];
+        let mut node = start;
+        let (tail_passes, loop_passes) = (0..)
+            .find_map(|passes| match started_pass[node as usize] {
+                Some(first) => Some((first, passes - first)),
+                None => {
+                    started_pass[node as usize] = Some(passes);
+                    node = network.after_passes(node, 1);
+                    None
+                }
+            })
+            .unwrap();
+        let length = loop_passes * pass;
+
+        let mut tail = tail_passes.saturating_sub(1) * pass;
+        let mut node = network.after_passes(start, tail / pass);
+        let mut ahead = network.after_passes(node, loop_passes);
+        while node != ahead {
+            node = network.step(node, tail);
+            ahead = network.step(ahead, tail);
+            tail += 1;
+        }
+
+        let (mut tail_hits, mut hits) = (vec![], vec![]);
+        let mut node = start;
+        for step in 0..tail + length {
+            if goals[node as usize] {
+                match step < tail {
+                    true => tail_hits.push(step),
+                    false => hits.push(step),
+                }
+            }
+            node = network.step(node, step);
+        }
+
+        Cycle {
+            tail,
+            length,
+            tail_hits,
+            hits,
+        }
+    }
+
+    fn is_goal_at(&self, step: usize) -> bool {
+        if step < self.tail {
+            self.tail_hits.contains(&step)
+        } else {
+            let offset = (step - self.tail) % self.length;
+            self.hits.contains(&(self.tail + offset))
+        }
+    }
+}
+
+// The combined modulus didn't fit
+struct Overflow;
+
+// Merges x ≡ a (mod m) with x ≡ b (mod n) into one congruence, if both can hold at once. `a` has
+// to be below `m`, and `n` a loop length.
+fn crt((a, m): (i128, i128), (b, n): (i128, i128)) -> Result<Option<(i128, i128)>, Overflow> {
+    let gcd = m.extended_gcd(&n);
+    if (b - a) % gcd.gcd != 0 {
+        return Ok(None);
+    }
+
+    let n_over_gcd = n / gcd.gcd;
+    let modulus = (m / gcd.gcd).checked_mul(n).ok_or(Overflow)?;
+    // Both factors are below a usize loop length, so their product fits a u128
+    let k = ((b - a) / gcd.gcd).rem_euclid(n_over_gcd) as u128
+        * gcd.x.rem_euclid(n_over_gcd) as u128
+        % n_over_gcd as u128;
+    // m * k is below the modulus, so only adding a can go past it
+    let residue = (m * k as i128).checked_add(a).ok_or(Overflow)? % modulus;
+    Ok(Some((residue, modulus)))
+}
+
+// Every step past the tails that's `residue` more than a multiple of `modulus` and fits a usize
+fn steps_after_tails(
+    residue: i128,
+    modulus: i128,
+    longest_tail: usize,
+) -> impl Iterator<Item = usize> {
+    let behind = (longest_tail as i128 - residue).max(0);
+    let loops = behind / modulus + i128::from(behind % modulus != 0);
+    let first = loops
+        .checked_mul(modulus)
+        .and_then(|step| step.checked_add(residue))
+        .and_then(|step| usize::try_from(step).ok());
+
+    let modulus = usize::try_from(modulus).ok();
+    std::iter::successors(first, move |step| step.checked_add(modulus?))
+}
+
+/// The first step at which every cycle is on a goal.
+///
+/// Before the longest tail is over, steps are simply checked one by one. After it, each cycle is
+/// on a goal at its loop hits plus any number of loop lengths, so the cycles are folded in one at
+/// a time with the Chinese Remainder Theorem, keeping only the distinct residues every cycle so
+/// far agrees on.
+///
+/// Once the combined modulus no longer fits in an i128 it's far past a usize, so each residue
+/// leaves at most a couple of steps that could be counted. Those are checked against the
+/// remaining cycles directly, and `Overflow` only comes back when no meeting fits.
+pub fn first_meeting(cycles: &[Cycle]) -> Steps {
+    let Some(longest_tail) = cycles.iter().map(|cycle| cycle.tail).max() else {
+        return Steps::Never;
+    };
+
+    if let Some(step) =
+        (0..longest_tail).find(|step| cycles.iter().all(|cycle| cycle.is_goal_at(*step)))
+    {
+        return Steps::After(step);
+    }
+
+    let mut modulus = 1;
+    let mut residues = BTreeSet::from([0]);
+    for (i, cycle) in cycles.iter().enumerate() {
+        let length = cycle.length as i128;
+        let mut agreed = BTreeSet::new();
+        let mut combined = modulus;
+        for residue in &residues {
+            for hit in &cycle.hits {
+                match crt((*residue, modulus), (*hit as i128, length)) {
+                    Ok(Some((residue, lcm))) => {
+                        agreed.insert(residue);
+                        combined = lcm;
+                    }
+                    Ok(None) => {}
+                    Err(Overflow) => {
+                        let rest = &cycles[i..];
+                        return residues
+                            .iter()
+                            .flat_map(|residue| steps_after_tails(*residue, modulus, longest_tail))
+                            .filter(|step| rest.iter().all(|cycle| cycle.is_goal_at(*step)))
+                            .min()
+                            .map_or(Steps::Overflow, Steps::After);
+                    }
+                }
+            }
+        }
+        if agreed.is_empty() {
+            return Steps::Never;
+        }
+        (modulus, residues) = (combined, agreed);
+    }
+
+    residues
+        .into_iter()
+        .filter_map(|residue| steps_after_tails(residue, modulus, longest_tail).next())
+        .min()
+        .map_or(Steps::Overflow, Steps::After)
+}
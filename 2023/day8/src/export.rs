@@ -0,0 +1,132 @@
+use crate::{Direction, Network, NodeId};
+use std::fmt::Write;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphFormat {
+    Dot,
+    Mermaid,
+}
+
+// Every node a walk from `start` could reach, whatever the directions
+fn reachable(network: &Network, start: NodeId) -> Vec<bool> {
+    let mut seen = vec![false; network.len()];
+    let mut to_visit = vec![start];
+
+    while let Some(id) = to_visit.pop() {
+        if seen[id as usize] {
+            continue;
+        }
+        seen[id as usize] = true;
+        to_visit.extend([Direction::Left, Direction::Right].map(|d| network.next(id, &d)));
+    }
+
+    seen
+}
+
+// Both of a node's edges, or one labelled "LR" when they lead to the same place
+fn edges(network: &Network, id: NodeId) -> Vec<(&'static str, NodeId)> {
+    let (left, right) = (
+        network.next(id, &Direction::Left),
+        network.next(id, &Direction::Right),
+    );
+    match left == right {
+        true => vec![("LR", left)],
+        false => vec![("L", left), ("R", right)],
+    }
+}
+
+// A DOT string's contents, with its quotes and backslashes escaped
+fn dot_string(name: &str) -> String {
+    name.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// Mermaid node labels are quoted text where only quotes need escaping, as an entity
+fn mermaid_label(name: &str) -> String {
+    name.replace('"', "#quot;")
+}
+
+/// The network as a graph, with ..A starts and ..Z ends highlighted. Given `from`, only what a
+/// ghost starting there could reach is drawn.
+pub fn graph(network: &Network, format: GraphFormat, from: Option<NodeId>) -> String {
+    let included = match from {
+        Some(start) => reachable(network, start),
+        None => vec![true; network.len()],
+    };
+    let nodes: Vec<NodeId> = network.ids().filter(|id| included[*id as usize]).collect();
+    let with_suffix = |suffix: &str| -> Vec<NodeId> {
+        nodes
+            .iter()
+            .copied()
+            .filter(|id| network.name(*id).ends_with(suffix))
+            .collect()
+    };
+    let (starts, ends) = (with_suffix("A"), with_suffix("Z"));
+
+    let mut graph = String::new();
+    match format {
+        GraphFormat::Dot => {
+            graph.push_str("digraph network {\n");
+            let name = |id: NodeId| dot_string(network.name(id));
+            for start in &starts {
+                writeln!(
+                    graph,
+                    "    \"{}\" [style=filled, fillcolor=palegreen];",
+                    name(*start)
+                )
+                .unwrap();
+            }
+            for end in &ends {
+                writeln!(
+                    graph,
+                    "    \"{}\" [style=filled, fillcolor=salmon];",
+                    name(*end)
+                )
+                .unwrap();
+            }
+            for id in &nodes {
+                for (label, next) in edges(network, *id) {
+                    writeln!(
+                        graph,
+                        "    \"{}\" -> \"{}\" [label=\"{}\"];",
+                        name(*id),
+                        name(next),
+                        label
+                    )
+                    .unwrap();
+                }
+            }
+            graph.push_str("}\n");
+        }
+        GraphFormat::Mermaid => {
+            // Node names can be Mermaid keywords like `end`, so nodes go by id with their name
+            // as a label
+            graph.push_str("flowchart LR\n");
+            for id in &nodes {
+                writeln!(
+                    graph,
+                    "    n{}[\"{}\"]",
+                    id,
+                    mermaid_label(network.name(*id))
+                )
+                .unwrap();
+            }
+            for id in &nodes {
+                for (label, next) in edges(network, *id) {
+                    writeln!(graph, "    n{} -->|{}| n{}", id, label, next).unwrap();
+                }
+            }
+            graph.push_str("    classDef start fill:#9f9\n    classDef goal fill:#f99\n");
+            let ids = |ids: &[NodeId]| -> Vec<String> {
+                ids.iter().map(|id| format!("n{}", id)).collect()
+            };
+            if !starts.is_empty() {
+                writeln!(graph, "    class {} start", ids(&starts).join(",")).unwrap();
+            }
+            if !ends.is_empty() {
+                writeln!(graph, "    class {} goal", ids(&ends).join(",")).unwrap();
+            }
+        }
+    }
+
+    graph
+}
@@ -0,0 +1,367 @@
+use common::{
+    parse::{self, Line, ParseError, ParseErrorKind},
+    Solution,
+};
+use std::{collections::hash_map::HashMap, fmt};
+
+mod cycles;
+mod export;
+mod network;
+mod walk;
+
+pub use cycles::{first_meeting, Cycle, Steps};
+pub use export::{graph, GraphFormat};
+pub use network::{Network, NodeId};
+pub use walk::{NodeMatcher, Step};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Direction::Left => write!(f, "L"),
+            Direction::Right => write!(f, "R"),
+        }
+    }
+}
+
+pub type Directions = Vec<Direction>;
+
+pub type NodeName = String;
+
+const START_NODE: &str = "AAA";
+const END_NODE: &str = "ZZZ";
+
+// Parsed before the names it mentions are known
+struct Node<'a> {
+    name: &'a str,
+    left: &'a str,
+    right: &'a str,
+}
+
+fn parse_directions(line: &Line) -> Result<Directions, ParseError> {
+    line.text
+        .char_indices()
+        .map(|(i, d)| match d {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(line.invalid("direction", &line.text[i..i + d.len_utf8()])),
+        })
+        .collect()
+}
+
+fn parse_node<'a>(line: &Line<'a>) -> Result<Node<'a>, ParseError> {
+    let mut split = line.text.split(" = ");
+
+    let name = split.next().unwrap();
+    let connections = split.next().ok_or_else(|| line.missing("connections"))?;
+    let node: Vec<&str> = connections
+        .strip_prefix("(")
+        .and_then(|node| node.strip_suffix(")"))
+        .ok_or_else(|| line.invalid("connections", connections))?
+        .split(", ")
+        .collect();
+
+    let [left, right] = node[..] else {
+        return Err(line.invalid("connections", connections));
+    };
+
+    Ok(Node { name, left, right })
+}
+
+fn parse_input(input: &str) -> Result<Network, ParseError> {
+    let lines: Vec<Line> = parse::lines(Day8::DAY, input).collect();
+
+    let directions: Directions = match lines.first() {
+        Some(line) if !line.text.is_empty() => parse_directions(line)?,
+        _ => {
+            return Err(ParseError {
+                day: Day8::DAY,
+                line: 1,
+                column: 1,
+                kind: ParseErrorKind::Missing { what: "directions" },
+            })
+        }
+    };
+
+    let nodes: Vec<Node> = lines
+        .iter()
+        .skip(2)
+        .map(parse_node)
+        .collect::<Result<_, _>>()?;
+    // A name defined twice would leave one of its nodes unreachable by name
+    let mut ids: HashMap<&str, NodeId> = HashMap::new();
+    for (id, (line, node)) in lines.iter().skip(2).zip(&nodes).enumerate() {
+        if let Some(first) = ids.insert(node.name, id as NodeId) {
+            return Err(line.error_at(
+                1,
+                ParseErrorKind::Conflict {
+                    what: "node",
+                    line: lines[first as usize + 2].number,
+                },
+            ));
+        }
+    }
+
+    // Every connection has to lead somewhere, or following it would get lost
+    let mut connections = vec![];
+    for (line, node) in lines.iter().skip(2).zip(&nodes) {
+        let id = |next_node: &str| {
+            ids.get(next_node).copied().ok_or_else(|| {
+                let start = line.text.find('(').unwrap();
+                let column = start + line.text[start..].find(next_node).unwrap();
+                line.invalid("node", &line.text[column..column + next_node.len()])
+            })
+        };
+        connections.push((id(node.left)?, id(node.right)?));
+    }
+
+    let names = nodes.iter().map(|node| node.name.into()).collect();
+    Ok(Network::new(directions, names, connections))
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Network;
+    type Output1 = Steps;
+    type Output2 = Steps;
+
+    fn parse(input: &str) -> Result<Network, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(network: &Network) -> Steps {
+        network.steps_until_all(
+            &NodeMatcher::Name(START_NODE.into()),
+            &NodeMatcher::Name(END_NODE.into()),
+        )
+    }
+
+    // Every ghost walks from its own ..A node at once, until they all stand on ..Z nodes
+    fn part2(network: &Network) -> Steps {
+        network.steps_until_all(
+            &NodeMatcher::Suffix("A".into()),
+            &NodeMatcher::Suffix("Z".into()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NETWORK: &str = "L
+
+11A = (11B, XXX)
+11B = (11Z, XXX)
+11Z = (11B, XXX)
+22A = (22Z, XXX)
+22Z = (22B, XXX)
+22B = (22C, XXX)
+22C = (22Z, XXX)
+33A = (33Z, XXX)
+33Z = (33B, XXX)
+33B = (33Z, XXX)
+XXX = (XXX, XXX)
+";
+
+    // Walks state by state until one repeats
+    fn tail_and_length(network: &Network, start: NodeId) -> (usize, usize) {
+        let mut seen = HashMap::new();
+        let mut node = start;
+        for step in 0.. {
+            let state = (node, step % network.directions.len());
+            if let Some(tail) = seen.insert(state, step) {
+                return (tail, step - tail);
+            }
+            node = network.step(node, step);
+        }
+        unreachable!()
+    }
+
+    #[test]
+    fn jumping_by_passes_finds_the_same_loops() {
+        for directions in ["L", "LR", "RLL", "LLRLR"] {
+            let input = NETWORK
+                .replacen("L", directions, 1)
+                .replace("XXX = (XXX, XXX)", "XXX = (22C, 11A)");
+            let network = Day8::parse(&input).unwrap();
+            let goals = network.goals(|node| node.ends_with("Z"));
+
+            for start in network.ids() {
+                let cycle = Cycle::find(&network, start, &goals);
+                assert_eq!(
+                    (cycle.tail, cycle.length),
+                    tail_and_length(&network, start),
+                    "{directions} from {}",
+                    network.name(start)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn meets_where_the_lcm_shortcut_fails() {
+        let network = Day8::parse(NETWORK).unwrap();
+        let goals = network.goals(|node| node.ends_with("Z"));
+        let cycle = |start| Cycle::find(&network, network.id(start).unwrap(), &goals);
+
+        assert_eq!(
+            cycle("22A"),
+            Cycle {
+                tail: 1,
+                length: 3,
+                tail_hits: vec![],
+                hits: vec![1],
+            }
+        );
+
+        // Even steps for the first ghost, one past a multiple of three for the second: not 6
+        assert_eq!(
+            first_meeting(&[cycle("11A"), cycle("22A")]),
+            Steps::After(4)
+        );
+        // Even steps against odd ones
+        assert_eq!(first_meeting(&[cycle("11A"), cycle("33A")]), Steps::Never);
+    }
+
+    #[test]
+    fn folds_many_ghosts_and_reports_overflow() {
+        let cycle = |length: usize, hits: Vec<usize>| Cycle {
+            tail: 0,
+            length,
+            tail_hits: vec![],
+            hits,
+        };
+
+        // 2^40 ways of picking a hit from each, but only two residues
+        let ghosts = vec![cycle(4, vec![1, 3]); 40];
+        assert_eq!(first_meeting(&ghosts), Steps::After(1));
+
+        // Every hit of a cycle has to survive, not just the first one to combine
+        assert_eq!(
+            first_meeting(&[cycle(4, vec![1, 3]), cycle(4, vec![3])]),
+            Steps::After(3)
+        );
+
+        // Four lengths whose product fits an i128 but not a usize, and a fifth past an i128
+        let primes = [
+            1_000_000_007,
+            998_244_353,
+            1_000_000_009,
+            999_999_937,
+            2_147_483_647,
+        ];
+        let huge = |count: usize, hit: fn(usize) -> usize| -> Vec<Cycle> {
+            primes[..count]
+                .iter()
+                .map(|p| cycle(*p, vec![hit(*p)]))
+                .collect()
+        };
+        assert_eq!(first_meeting(&huge(4, |_| 1)), Steps::After(1));
+        assert_eq!(first_meeting(&huge(4, |p| p - 1)), Steps::Overflow);
+        assert_eq!(first_meeting(&huge(5, |_| 1)), Steps::After(1));
+        assert_eq!(first_meeting(&huge(5, |p| p - 1)), Steps::Overflow);
+    }
+
+    #[test]
+    fn meets_on_any_goal_of_a_loop() {
+        let network = Day8::parse(
+            "L
+
+1AA = (1PZ, 1AA)
+1PZ = (1BB, 1PZ)
+1BB = (1QZ, 1BB)
+1QZ = (1AA, 1QZ)
+2AA = (2BB, 2AA)
+2BB = (2CC, 2BB)
+2CC = (2ZZ, 2CC)
+2ZZ = (2AA, 2ZZ)
+",
+        )
+        .unwrap();
+
+        assert_eq!(Day8::part2(&network), Steps::After(3));
+    }
+
+    #[test]
+    fn rejects_a_node_defined_twice() {
+        let input = NETWORK.replace("XXX = (XXX, XXX)", "22B = (XXX, XXX)");
+
+        assert_eq!(
+            Day8::parse(&input).unwrap_err().to_string(),
+            "day8: line 13, column 1: node conflicts with line 8"
+        );
+    }
+
+    #[test]
+    fn graphs_only_what_a_start_reaches() {
+        let network = Day8::parse(NETWORK).unwrap();
+
+        assert_eq!(
+            graph(&network, GraphFormat::Mermaid, network.id("33A")),
+            r#"flowchart LR
+    n7["33A"]
+    n8["33Z"]
+    n9["33B"]
+    n10["XXX"]
+    n7 -->|L| n8
+    n7 -->|R| n10
+    n8 -->|L| n9
+    n8 -->|R| n10
+    n9 -->|L| n8
+    n9 -->|R| n10
+    n10 -->|LR| n10
+    classDef start fill:#9f9
+    classDef goal fill:#f99
+    class n7 start
+    class n8 goal
+"#
+        );
+        assert!(graph(&network, GraphFormat::Dot, None)
+            .contains("    \"22Z\" [style=filled, fillcolor=salmon];\n"));
+
+        // Names that are Mermaid keywords or hold quotes and backslashes
+        let network = Day8::parse("L\n\nend = (a\"\\Z, end)\na\"\\Z = (end, end)\n").unwrap();
+        let mermaid = graph(&network, GraphFormat::Mermaid, None);
+        assert!(mermaid.contains("    n0[\"end\"]\n    n1[\"a#quot;\\Z\"]\n"));
+        assert!(mermaid.contains("    class n1 goal\n"));
+        assert!(graph(&network, GraphFormat::Dot, None)
+            .contains("    \"end\" -> \"a\\\"\\\\Z\" [label=\"L\"];\n"));
+    }
+
+    #[test]
+    fn traces_a_ghost_to_any_matching_goal() {
+        let network = Day8::parse(NETWORK).unwrap();
+        let start = network.id("22A").unwrap();
+        let names = |path: Vec<Step>| -> Vec<&str> {
+            path.iter().map(|step| network.name(step.node)).collect()
+        };
+
+        let to_22z: NodeMatcher = "22Z".parse().unwrap();
+        assert_eq!(names(network.path(start, &to_22z).unwrap()), ["22Z"]);
+
+        let to_c: NodeMatcher = "regex:^2.C$".parse().unwrap();
+        let path = network.path(start, &to_c).unwrap();
+        assert_eq!(
+            path.last(),
+            Some(&Step {
+                index: 0,
+                direction: Direction::Left,
+                node: network.id("22C").unwrap(),
+            })
+        );
+        assert_eq!(names(path), ["22Z", "22B", "22C"]);
+
+        let nowhere: NodeMatcher = "suffix:Q".parse().unwrap();
+        assert_eq!(network.path(start, &nowhere), None);
+        assert_eq!(nowhere.to_string(), "suffix:Q");
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{Direction, Directions, NodeName};
+use std::collections::HashMap;
+
+pub type NodeId = u32;
+
+/// The map's nodes, interned to dense ids so a step is an array lookup rather than a string hash.
+#[derive(Debug)]
+pub struct Network {
+    pub directions: Directions,
+    names: Vec<NodeName>,
+    ids: HashMap<NodeName, NodeId>,
+    left: Vec<NodeId>,
+    right: Vec<NodeId>,
+    // Where each node ends up after following every direction once
+    after_pass: Vec<NodeId>,
+}
+
+impl Network {
+    /// `connections` holds each node's left and right neighbours, in the order of `names`, which
+    /// have to be distinct.
+    pub fn new(
+        directions: Directions,
+        names: Vec<NodeName>,
+        connections: Vec<(NodeId, NodeId)>,
+    ) -> Network {
+        let ids = names
+            .iter()
+            .enumerate()
+            .map(|(id, name)| (name.clone(), id as NodeId))
+            .collect();
+        let (left, right) = connections.into_iter().unzip();
+
+        let mut network = Network {
+            directions,
+            names,
+            ids,
+            left,
+            right,
+            after_pass: vec![],
+        };
+        network.after_pass = network
+            .ids()
+            .map(|id| {
+                network
+                    .directions
+                    .iter()
+                    .fold(id, |id, direction| network.next(id, direction))
+            })
+            .collect();
+
+        network
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = NodeId> {
+        0..self.len() as NodeId
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id as usize]
+    }
+
+    pub fn next(&self, id: NodeId, direction: &Direction) -> NodeId {
+        match direction {
+            Direction::Left => self.left[id as usize],
+            Direction::Right => self.right[id as usize],
+        }
+    }
+
+    /// Which nodes `is_goal` holds for, indexed by id.
+    pub fn goals(&self, is_goal: impl Fn(&str) -> bool) -> Vec<bool> {
+        self.names.iter().map(|name| is_goal(name)).collect()
+    }
+
+    /// Follows the direction at `step`, wrapping round the directions.
+    pub fn step(&self, id: NodeId, step: usize) -> NodeId {
+        self.next(id, &self.directions[step % self.directions.len()])
+    }
+
+    /// Where `passes` whole runs through the directions lead, one table lookup a pass.
+    pub fn after_passes(&self, id: NodeId, passes: usize) -> NodeId {
+        (0..passes).fold(id, |id, _| self.after_pass[id as usize])
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{first_meeting, Cycle, Direction, Network, NodeId, Steps};
+use rayon::prelude::*;
+use regex::Regex;
+use std::{fmt, str::FromStr};
+
+/// Picks nodes out by name. Written as `AAA` for exactly that name, `suffix:Z` or `regex:^1.Z$`.
+#[derive(Clone, Debug)]
+pub enum NodeMatcher {
+    Name(String),
+    Suffix(String),
+    Regex(Regex),
+}
+
+/// One step of a walk: following the direction at `index` led to `node`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub index: usize,
+    pub direction: Direction,
+    pub node: NodeId,
+}
+
+impl NodeMatcher {
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            NodeMatcher::Name(wanted) => name == wanted,
+            NodeMatcher::Suffix(suffix) => name.ends_with(suffix.as_str()),
+            NodeMatcher::Regex(regex) => regex.is_match(name),
+        }
+    }
+}
+
+impl FromStr for NodeMatcher {
+    type Err = regex::Error;
+
+    fn from_str(text: &str) -> Result<NodeMatcher, regex::Error> {
+        if let Some(suffix) = text.strip_prefix("suffix:") {
+            Ok(NodeMatcher::Suffix(suffix.into()))
+        } else if let Some(regex) = text.strip_prefix("regex:") {
+            Ok(NodeMatcher::Regex(Regex::new(regex)?))
+        } else {
+            Ok(NodeMatcher::Name(text.into()))
+        }
+    }
+}
+
+impl fmt::Display for NodeMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NodeMatcher::Name(name) => write!(f, "{}", name),
+            NodeMatcher::Suffix(suffix) => write!(f, "suffix:{}", suffix),
+            NodeMatcher::Regex(regex) => write!(f, "regex:{}", regex),
+        }
+    }
+}
+
+impl Network {
+    pub fn matching(&self, matcher: &NodeMatcher) -> Vec<NodeId> {
+        self.ids()
+            .filter(|id| matcher.matches(self.name(*id)))
+            .collect()
+    }
+
+    /// Every step from `start` up to the first node `goal` matches, or `None` if the walk loops
+    /// forever without reaching one.
+    pub fn path(&self, start: NodeId, goal: &NodeMatcher) -> Option<Vec<Step>> {
+        let goals = self.goals(|name| goal.matches(name));
+        let cycle = Cycle::find(self, start, &goals);
+        let length = *cycle.tail_hits.first().or(cycle.hits.first())?;
+
+        let mut node = start;
+        let path = (0..length)
+            .map(|step| {
+                let index = step % self.directions.len();
+                node = self.step(node, step);
+                Step {
+                    index,
+                    direction: self.directions[index],
+                    node,
+                }
+            })
+            .collect();
+
+        Some(path)
+    }
+
+    /// Walks a ghost from every node `start` matches at once, until they all stand on nodes
+    /// `goal` matches.
+    pub fn steps_until_all(&self, start: &NodeMatcher, goal: &NodeMatcher) -> Steps {
+        let goals = self.goals(|name| goal.matches(name));
+
+        let cycles: Vec<Cycle> = self
+            .matching(start)
+            .par_iter()
+            .map(|start| Cycle::find(self, *start, &goals))
+            .collect();
+
+        first_meeting(&cycles)
+    }
+}
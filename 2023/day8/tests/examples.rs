@@ -0,0 +1,7 @@
+use common::examples;
+use day8::Day8;
+
+#[test]
+fn examples() {
+    examples::check::<Day8>(concat!(env!("CARGO_MANIFEST_DIR"), "/data"));
+}
@@ -0,0 +1,179 @@
+use common::{
+    parse::{self, ParseError},
+    Solution,
+};
+use std::fmt;
+
+pub type Value = i128;
+pub type History = Vec<Value>;
+pub type Histories = Vec<History>;
+
+/// The polynomial through a history's values, kept as its Newton forward differences at the
+/// first value: `f(0)`, `Δf(0)`, `Δ²f(0)`, ... up to the last one that isn't zero.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polynomial {
+    differences: Vec<Value>,
+    len: usize,
+}
+
+fn parse_input(input: &str) -> Result<Histories, ParseError> {
+    parse::lines(Day9::DAY, input)
+        .map(|line| {
+            line.text
+                .split_whitespace()
+                .map(|value| line.parse("value", value))
+                .collect()
+        })
+        .collect()
+}
+
+fn get_diff(history: &[Value]) -> Option<History> {
+    history
+        .windows(2)
+        .map(|pair| pair[1].checked_sub(pair[0]))
+        .collect()
+}
+
+impl Polynomial {
+    /// `None` if the differences between values don't fit in an i128.
+    pub fn fit(history: &[Value]) -> Option<Polynomial> {
+        let mut differences = vec![];
+
+        let mut curr_diff = history.to_vec();
+        while curr_diff.iter().any(|value| *value != 0) {
+            differences.push(curr_diff[0]);
+            curr_diff = get_diff(&curr_diff)?;
+        }
+
+        Some(Polynomial {
+            differences,
+            len: history.len(),
+        })
+    }
+
+    /// The value at position `x`, where the history covers `0..len`. Any position works, before
+    /// the start as well: f(x) = Σ C(x, j) Δʲf(0). `None` if it doesn't fit in an i128.
+    pub fn at(&self, x: i128) -> Option<Value> {
+        let mut value: Value = 0;
+        // C(x, j), built up as C(x, j - 1) * (x - j + 1) / j, which always divides exactly
+        let mut binomial: i128 = 1;
+
+        for (j, difference) in self.differences.iter().enumerate() {
+            if j > 0 {
+                let j = j as i128;
+                binomial = binomial.checked_mul(x - j + 1)? / j;
+            }
+            value = value.checked_add(binomial.checked_mul(*difference)?)?;
+        }
+
+        Some(value)
+    }
+
+    /// `k` steps past the last value.
+    pub fn after(&self, k: usize) -> Option<Value> {
+        self.at(self.len as i128 + k as i128 - 1)
+    }
+
+    /// `k` steps before the first value.
+    pub fn before(&self, k: usize) -> Option<Value> {
+        self.at(-(k as i128))
+    }
+}
+
+/// Every history's prediction added up, or `Overflow` when that doesn't fit in an i128.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Total {
+    Sum(Value),
+    Overflow,
+}
+
+impl fmt::Display for Total {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Total::Sum(sum) => write!(f, "{}", sum),
+            Total::Overflow => write!(f, "too large for an i128"),
+        }
+    }
+}
+
+fn total(histories: &Histories, predict: impl Fn(&Polynomial) -> Option<Value>) -> Total {
+    histories
+        .iter()
+        .try_fold(0, |sum: Value, history| {
+            sum.checked_add(predict(&Polynomial::fit(history)?)?)
+        })
+        .map_or(Total::Overflow, Total::Sum)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Histories;
+    type Output1 = Total;
+    type Output2 = Total;
+
+    fn parse(input: &str) -> Result<Histories, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(histories: &Histories) -> Total {
+        total(histories, |polynomial| polynomial.after(1))
+    }
+
+    fn part2(histories: &Histories) -> Total {
+        total(histories, |polynomial| polynomial.before(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Extends the difference pyramid one value past the end, the way it's done by hand
+    fn extrapolate_by_pyramid(history: &[Value]) -> Value {
+        let mut extrapolation = 0;
+        let mut curr_diff = history.to_vec();
+        while curr_diff.iter().any(|value| *value != 0) {
+            extrapolation += curr_diff.last().unwrap();
+            curr_diff = get_diff(&curr_diff).unwrap();
+        }
+        extrapolation
+    }
+
+    #[test]
+    fn matches_the_difference_pyramid_both_ways() {
+        let histories = Day9::parse(include_str!("../data/part1-test")).unwrap();
+
+        for history in &histories {
+            let polynomial = Polynomial::fit(history).unwrap();
+            let reversed: History = history.iter().rev().copied().collect();
+
+            assert_eq!(polynomial.after(1), Some(extrapolate_by_pyramid(history)));
+            assert_eq!(
+                polynomial.before(1),
+                Some(extrapolate_by_pyramid(&reversed))
+            );
+        }
+    }
+
+    #[test]
+    fn predicts_any_number_of_steps() {
+        let cubes = Polynomial::fit(&[0, 1, 8, 27, 64]).unwrap();
+
+        assert_eq!(cubes.after(3), Some(343));
+        assert_eq!(cubes.before(4), Some(-64));
+        assert_eq!(cubes.after(1_000_000), Some(1_000_004_i128.pow(3)));
+        assert_eq!(cubes.after(usize::MAX), None);
+        assert_eq!(Polynomial::fit(&[]).unwrap().after(5), Some(0));
+
+        let big = vec![vec![0, Value::MAX / 2]; 2];
+        assert_eq!(Day9::part1(&big), Total::Overflow);
+        assert_eq!(Polynomial::fit(&[Value::MIN, Value::MAX]), None);
+        assert_eq!(
+            Day9::part2(&big[..1].to_vec()),
+            Total::Sum(-(Value::MAX / 2))
+        );
+    }
+}
@@ -0,0 +1,7 @@
+use common::examples;
+use day9::Day9;
+
+#[test]
+fn examples() {
+    examples::check::<Day9>(concat!(env!("CARGO_MANIFEST_DIR"), "/data"));
+}
@@ -0,0 +1,315 @@
+use common::parse::{self, ParseError};
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Pos {
+        Pos { row, col }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+        }
+    }
+}
+
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid stored row by row, indexed by `Pos`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    num_rows: usize,
+    num_cols: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let num_rows = rows.len();
+        let num_cols = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == num_cols),
+            "All rows of a grid must be the same length"
+        );
+
+        Grid {
+            cells: rows.into_iter().flatten().collect(),
+            num_rows,
+            num_cols,
+        }
+    }
+
+    /// Parses one cell per character, reporting characters `cell` doesn't accept as an invalid
+    /// `what`, and rows of differing lengths.
+    pub fn parse(
+        day: u8,
+        input: &str,
+        what: &'static str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut rows = vec![];
+
+        for line in parse::lines(day, input) {
+            let row: Vec<T> = line
+                .text
+                .char_indices()
+                .map(|(col, ch)| {
+                    cell(ch).ok_or_else(|| line.invalid(what, &line.text[col..col + ch.len_utf8()]))
+                })
+                .collect::<Result<_, _>>()?;
+
+            if rows
+                .first()
+                .is_some_and(|first: &Vec<T>| first.len() != row.len())
+            {
+                return Err(line.invalid("row length", line.text));
+            }
+            rows.push(row);
+        }
+
+        Ok(Grid::from_rows(rows))
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    pub fn num_cols(&self) -> usize {
+        self.num_cols
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.num_rows && pos.col < self.num_cols
+    }
+
+    /// Whether `pos` lies on the outermost rows or columns.
+    pub fn is_edge(&self, pos: Pos) -> bool {
+        pos.row == 0 || pos.col == 0 || pos.row + 1 == self.num_rows || pos.col + 1 == self.num_cols
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.row * self.num_cols + pos.col])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.row * self.num_cols + pos.col])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.num_cols..(row + 1) * self.num_cols]
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.num_cols, "Column {} out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.num_cols)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0, an empty grid has no rows to yield anyway
+        self.cells.chunks(self.num_cols.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.num_cols).map(|col| self.column(col))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let num_cols = self.num_cols;
+        (0..self.num_rows).flat_map(move |row| (0..num_cols).map(move |col| Pos { row, col }))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// First position, row by row, whose cell matches.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    fn offset(&self, pos: Pos, (d_row, d_col): (isize, isize)) -> Option<Pos> {
+        let next = Pos {
+            row: pos.row.checked_add_signed(d_row)?,
+            col: pos.col.checked_add_signed(d_col)?,
+        };
+        self.contains(next).then_some(next)
+    }
+
+    /// The neighbouring position in `direction`, if it's still on the grid.
+    pub fn step(&self, pos: Pos, direction: Direction) -> Option<Pos> {
+        self.offset(pos, direction.offset())
+    }
+
+    /// Up, down, left and right neighbours that are on the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .iter()
+            .filter_map(move |direction| self.step(pos, *direction))
+    }
+
+    /// Neighbours including diagonals that are on the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_8
+            .iter()
+            .filter_map(move |offset| self.offset(pos, *offset))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            num_rows: self.num_rows,
+            num_cols: self.num_cols,
+        }
+    }
+
+    /// One line per row with a character per cell.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut rendered = String::with_capacity(self.num_rows * (self.num_cols + 1));
+        for row in self.rows() {
+            rendered.extend(row.iter().map(&mut f));
+            rendered.push('\n');
+        }
+        rendered
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(num_rows: usize, num_cols: usize, value: T) -> Grid<T> {
+        Grid {
+            cells: vec![value; num_rows * num_cols],
+            num_rows,
+            num_cols,
+        }
+    }
+
+    /// Rows become columns, so column-wise work can reuse row-wise code.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_rows(
+            self.columns()
+                .map(|column| column.cloned().collect())
+                .collect(),
+        )
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} out of bounds", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} out of bounds", pos))
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render(|ch| *ch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse(0, "abc\ndef\n", "cell", Some).unwrap()
+    }
+
+    #[test]
+    fn neighbours_stop_at_edges() {
+        let grid = grid();
+
+        let corner: Vec<_> = grid.neighbours8(Pos::new(0, 0)).collect();
+        assert_eq!(corner, vec![Pos::new(0, 1), Pos::new(1, 0), Pos::new(1, 1)]);
+        assert_eq!(grid.neighbours4(Pos::new(1, 1)).count(), 3);
+        assert_eq!(grid.neighbours8(Pos::new(0, 1)).count(), 5);
+        assert_eq!(grid.step(Pos::new(1, 2), Direction::Right), None);
+    }
+
+    #[test]
+    fn transposes_and_renders() {
+        let grid = grid();
+        let transposed = grid.transpose();
+
+        assert_eq!(transposed.to_string(), "ad\nbe\ncf\n");
+        assert_eq!(transposed.transpose(), grid);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+    }
+
+    #[test]
+    fn rejects_ragged_rows_and_unknown_cells() {
+        let ragged = Grid::parse(3, "ab\nabc\n", "cell", Some).unwrap_err();
+        assert_eq!(
+            ragged.to_string(),
+            "day3: line 2, column 1: invalid row length 'abc'"
+        );
+
+        let unknown = Grid::parse(3, "..\n.x\n", "cell", |ch| (ch == '.').then_some(ch));
+        assert_eq!(
+            unknown.unwrap_err().to_string(),
+            "day3: line 2, column 2: invalid cell 'x'"
+        );
+    }
+}